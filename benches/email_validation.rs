@@ -1,5 +1,7 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use email_syntax_verify_opt::simd::{all_domain_chars, all_user_chars, find_byte, has_dot_run};
 use email_syntax_verify_opt::validate_email as our_validate_email;
+use email_syntax_verify_opt::{extract_emails, EmailValidator};
 use regex::Regex;
 
 fn validator_crate_validate(email: &str) -> bool {
@@ -212,12 +214,89 @@ fn bench_email_length_impact(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_simd_scanning(c: &mut Criterion) {
+    let local = "first.last+newsletter.tag".repeat(2);
+    let domain = "mail-relay.eu-west.internal.example.".repeat(5) + "com";
+    let long_email = format!("{local}@{domain}");
+
+    let mut group = c.benchmark_group("simd_scanning");
+
+    group.bench_function("validate_long_address", |b| {
+        b.iter(|| black_box(our_validate_email(black_box(&long_email))))
+    });
+
+    group.bench_function("user_class", |b| {
+        b.iter(|| black_box(all_user_chars(black_box(local.as_bytes()))))
+    });
+
+    group.bench_function("domain_class", |b| {
+        b.iter(|| black_box(all_domain_chars(black_box(domain.as_bytes()))))
+    });
+
+    group.bench_function("dot_run", |b| {
+        b.iter(|| black_box(has_dot_run(black_box(domain.as_bytes()))))
+    });
+
+    let log = "2024-01-01T00:00:00Z INFO request handled in 12ms path=/api/v1/items status=200\n"
+        .repeat(64)
+        + "contact ops@example.com";
+
+    group.bench_function("find_at_in_log", |b| {
+        b.iter(|| black_box(find_byte(black_box(log.as_bytes()), b'@')))
+    });
+
+    group.bench_function("extract_from_log", |b| {
+        b.iter(|| black_box(extract_emails(black_box(&log)).count()))
+    });
+
+    group.finish();
+}
+
+/// The batch entry points against a plain loop over `validate_email`. With
+/// AVX2 the batch path classifies four addresses per step and accepts short
+/// ASCII ones from their byte masks, so it should beat the loop.
+fn bench_batch_validation(c: &mut Criterion) {
+    let emails: Vec<&str> = REALISTIC_EMAILS
+        .iter()
+        .chain(VALID_EMAILS)
+        .chain(INVALID_EMAILS)
+        .copied()
+        .cycle()
+        .take(10_000)
+        .collect();
+
+    let mut group = c.benchmark_group("batch_validation");
+    group.throughput(Throughput::Elements(emails.len() as u64));
+
+    group.bench_function("baseline_loop_validate_email", |b| {
+        b.iter(|| {
+            emails
+                .iter()
+                .map(|email| our_validate_email(black_box(email)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    group.bench_function("validate_batch", |b| {
+        b.iter(|| EmailValidator::validate_batch(black_box(&emails)))
+    });
+
+    let mut results = vec![false; emails.len()];
+    group.bench_function("validate_batch_into", |b| {
+        b.iter(|| EmailValidator::validate_batch_into(black_box(&emails), &mut results))
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_comparison_valid_emails,
     bench_comparison_invalid_emails,
     bench_comparison_realistic_emails,
     bench_single_email_comparison,
-    bench_email_length_impact
+    bench_email_length_impact,
+    bench_simd_scanning,
+    bench_batch_validation
 );
 criterion_main!(benches);
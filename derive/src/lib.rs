@@ -0,0 +1,234 @@
+//! `#[derive(ValidateEmails)]` for `email_syntax_verify_opt`, re-exported by
+//! that crate under its `derive` feature.
+
+#![forbid(unsafe_code)]
+#![deny(clippy::all, clippy::pedantic, clippy::nursery)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Expr, Fields, GenericArgument, LitStr, Member,
+    PathArguments, Type,
+};
+
+/// Implements `ValidateEmails` by checking every field marked `#[email]`.
+///
+/// Marked fields may be any `ValidateEmail` type, or `Option`s, `Box`es,
+/// references, `Vec`s, slices and arrays of one, nested as deep as needed.
+/// Collection elements are reported as `field[index]`, except for bytes:
+/// `Vec<u8>` and `[u8]` are addresses themselves. `#[email(policy = "...")]`
+/// takes an expression evaluating to a `ValidationPolicy`, on a field or on the
+/// struct to set the default for all its fields.
+#[proc_macro_derive(ValidateEmails, attributes(email))]
+pub fn derive_validate_emails(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Options from one `#[email(...)]` attribute.
+struct EmailAttr {
+    policy: Option<Expr>,
+}
+
+fn parse_email_attrs(attrs: &[Attribute]) -> syn::Result<Option<EmailAttr>> {
+    let mut found = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("email")) {
+        if found.is_some() {
+            return Err(syn::Error::new(attr.span(), "duplicate `email` attribute"));
+        }
+
+        let mut email = EmailAttr { policy: None };
+        if !matches!(attr.meta, syn::Meta::Path(_)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("policy") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    email.policy = Some(value.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `email` option, expected `policy`"))
+                }
+            })?;
+        }
+        found = Some(email);
+    }
+    Ok(found)
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`ValidateEmails` can only be derived for structs",
+        ));
+    };
+
+    let default_policy = parse_email_attrs(&input.attrs)?
+        .and_then(|attr| attr.policy)
+        .map_or_else(
+            || quote!(::email_syntax_verify_opt::ValidationPolicy::DEFAULT),
+            |policy| quote!(#policy),
+        );
+
+    let mut checks = Vec::new();
+    let mut bounds = Vec::new();
+    let fields = match &data.fields {
+        Fields::Named(fields) => &fields.named,
+        Fields::Unnamed(fields) => &fields.unnamed,
+        Fields::Unit => return Ok(impl_block(input, &checks, &bounds)),
+    };
+
+    for (index, field) in fields.iter().enumerate() {
+        let Some(attr) = parse_email_attrs(&field.attrs)? else {
+            continue;
+        };
+
+        let member = field
+            .ident
+            .clone()
+            .map_or_else(|| Member::from(index), Member::Named);
+        let path = match &member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let policy = attr
+            .policy
+            .map_or_else(|| default_policy.clone(), |policy| quote!(#policy));
+
+        let check = check_value(
+            &field.ty,
+            &quote!(&self.#member),
+            &path,
+            &quote!(&mut errors),
+            &mut bounds,
+        );
+        checks.push(quote! {
+            {
+                let policy: ::email_syntax_verify_opt::ValidationPolicy = #policy;
+                #check
+            }
+        });
+    }
+
+    Ok(impl_block(input, &checks, &bounds))
+}
+
+/// Generates the check of `value`, a reference to a `ty`, pushing failures
+/// onto `errors` under `path` and requiring `ValidateEmail` of the leaf type.
+fn check_value(
+    ty: &Type,
+    value: &TokenStream2,
+    path: &str,
+    errors: &TokenStream2,
+    bounds: &mut Vec<TokenStream2>,
+) -> TokenStream2 {
+    match ty {
+        Type::Group(group) => check_value(&group.elem, value, path, errors, bounds),
+        Type::Paren(paren) => check_value(&paren.elem, value, path, errors, bounds),
+        Type::Reference(reference) => {
+            check_value(&reference.elem, &quote!(&**#value), path, errors, bounds)
+        }
+        Type::Slice(slice) if !is_byte(&slice.elem) => {
+            check_each(&slice.elem, value, path, errors, bounds)
+        }
+        Type::Array(array) if !is_byte(&array.elem) => {
+            check_each(&array.elem, value, path, errors, bounds)
+        }
+        _ => {
+            if let Some(inner) = wrapped_type(ty, "Option") {
+                let check = check_value(inner, &quote!(item), path, errors, bounds);
+                quote! {
+                    if let ::core::option::Option::Some(item) = #value {
+                        #check
+                    }
+                }
+            } else if let Some(inner) = wrapped_type(ty, "Box") {
+                check_value(inner, &quote!(&**#value), path, errors, bounds)
+            } else if let Some(inner) = wrapped_type(ty, "Vec").filter(|inner| !is_byte(inner)) {
+                check_each(inner, value, path, errors, bounds)
+            } else {
+                bounds.push(quote!(#ty: ::email_syntax_verify_opt::ValidateEmail));
+                quote! {
+                    if let ::core::result::Result::Err(error) =
+                        ::email_syntax_verify_opt::ValidateEmail::check_email(#value, policy)
+                    {
+                        ::email_syntax_verify_opt::__private::Vec::push(
+                            #errors,
+                            ::email_syntax_verify_opt::EmailFieldError::new(#path, error),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_each(
+    elem: &Type,
+    value: &TokenStream2,
+    path: &str,
+    errors: &TokenStream2,
+    bounds: &mut Vec<TokenStream2>,
+) -> TokenStream2 {
+    let check = check_value(elem, &quote!(item), path, &quote!(errors), bounds);
+    quote! {
+        ::email_syntax_verify_opt::traits::check_indexed(#value, #path, #errors, |item, errors| {
+            #check
+        });
+    }
+}
+
+/// The type argument of `ty` if it is `name<T>`, matched by last segment.
+fn wrapped_type<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+fn is_byte(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("u8"))
+}
+
+fn impl_block(
+    input: &DeriveInput,
+    checks: &[TokenStream2],
+    bounds: &[TokenStream2],
+) -> TokenStream2 {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause
+        .into_iter()
+        .flat_map(|clause| &clause.predicates);
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::email_syntax_verify_opt::ValidateEmails for #name #ty_generics
+        where
+            #(#predicates,)*
+            #(#bounds,)*
+        {
+            fn validate_emails(
+                &self,
+            ) -> ::core::result::Result<(), ::email_syntax_verify_opt::EmailFieldErrors> {
+                #[allow(unused_mut)]
+                let mut errors = ::email_syntax_verify_opt::__private::Vec::new();
+                #(#checks)*
+                ::email_syntax_verify_opt::EmailFieldErrors::check(errors)
+            }
+        }
+    }
+}
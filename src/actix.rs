@@ -0,0 +1,111 @@
+//! Actix Web extractors, enabled with the `actix-web` feature.
+//!
+//! [`EmailQuery`] and [`EmailPath`] deserialize exactly like `web::Query` and
+//! `web::Path`, then run [`ValidateEmails`] on the result. Malformed input is
+//! rejected as those extractors would reject it; invalid addresses get
+//! `422 Unprocessable Entity` with a JSON body naming each failing field and
+//! its [`EmailValidationError::code`](crate::EmailValidationError::code).
+//! Invalid [`Email`](crate::Email) values, which fail deserialization, are
+//! reported the same way under the name of the parameter they came from.
+
+use crate::error::{EmailFieldErrors, EmailValidationError};
+use crate::serde::{collect_rejected, rejected_fields};
+use crate::traits::ValidateEmails;
+use ::actix_web::dev::Payload;
+use ::actix_web::error::PathError;
+use ::actix_web::http::StatusCode;
+use ::actix_web::{web, Error, FromRequest, HttpRequest, HttpResponse, ResponseError};
+use ::serde::de::DeserializeOwned;
+use std::future::{ready, Ready};
+
+impl ResponseError for EmailFieldErrors {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type("application/json")
+            .body(self.to_json())
+    }
+}
+
+/// Reports addresses rejected while deserializing as field errors, falling
+/// back to the extractor's own `rejection` when there were none.
+#[cold]
+#[inline(never)]
+fn rejection_error(
+    rejection: impl Into<Error>,
+    rejected: Vec<(String, EmailValidationError)>,
+    params: &[(&str, &str)],
+) -> Error {
+    match rejected_fields(rejected, params) {
+        Err(errors) => errors.into(),
+        Ok(()) => rejection.into(),
+    }
+}
+
+fn validated<T: ValidateEmails>(value: T) -> Result<T, Error> {
+    value.validate_emails()?;
+    Ok(value)
+}
+
+/// Query string parameters with validated address fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmailQuery<T>(pub T);
+
+impl<T> FromRequest for EmailQuery<T>
+where
+    T: DeserializeOwned + ValidateEmails,
+{
+    type Error = Error;
+    type Future = Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let mut rejected = Vec::new();
+        let query = collect_rejected(&mut rejected, || {
+            web::Query::<T>::from_query(req.query_string())
+        });
+        ready(
+            query
+                .map_err(|rejection| {
+                    let params =
+                        web::Query::<Vec<(String, String)>>::from_query(req.query_string())
+                            .map(web::Query::into_inner)
+                            .unwrap_or_default();
+                    let params: Vec<_> = params
+                        .iter()
+                        .map(|(name, value)| (name.as_str(), value.as_str()))
+                        .collect();
+                    rejection_error(rejection, rejected, &params)
+                })
+                .and_then(|query| validated(query.into_inner()))
+                .map(Self),
+        )
+    }
+}
+
+/// Path parameters with validated address fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmailPath<T>(pub T);
+
+impl<T> FromRequest for EmailPath<T>
+where
+    T: DeserializeOwned + ValidateEmails,
+{
+    type Error = Error;
+    type Future = Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let mut rejected = Vec::new();
+        let path = collect_rejected(&mut rejected, || req.match_info().load::<T>());
+        ready(
+            path.map_err(|err| {
+                let params: Vec<_> = req.match_info().iter().collect();
+                rejection_error(PathError::Deserialize(err), rejected, &params)
+            })
+            .and_then(validated)
+            .map(Self),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(feature = "alloc")]
+use crate::idn;
+use crate::ip::IpLiteral;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmailAddress<'a> {
+    address: &'a str,
+    at_pos: usize,
+    ip_literal: Option<IpLiteral<'a>>,
+}
+
+impl<'a> EmailAddress<'a> {
+    #[inline(always)]
+    pub(crate) const fn new(
+        address: &'a str,
+        at_pos: usize,
+        ip_literal: Option<IpLiteral<'a>>,
+    ) -> Self {
+        Self {
+            address,
+            at_pos,
+            ip_literal,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.address
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn local_part(&self) -> &'a str {
+        &self.address[..self.at_pos]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn domain(&self) -> &'a str {
+        &self.address[self.at_pos + 1..]
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn ip_literal(&self) -> Option<IpLiteral<'a>> {
+        self.ip_literal
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_ip_literal(&self) -> bool {
+        self.ip_literal.is_some()
+    }
+
+    /// Whether the domain is an internationalized domain name.
+    #[inline]
+    #[must_use]
+    pub fn is_idn(&self) -> bool {
+        !self.domain().is_ascii()
+    }
+
+    /// Returns the address in a form suitable for comparison and storage.
+    ///
+    /// The local part is kept as written, since RFC 5321 leaves its case
+    /// significant. The domain is lowercased and internationalized names are
+    /// converted to their ASCII (punycode) form; IP literals are rewritten in
+    /// their shortest notation, so `[IPv6:0:0::1]` becomes `[IPv6:::1]`.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn canonical(&self) -> String {
+        let mut out = String::with_capacity(self.address.len());
+        out.push_str(self.local_part());
+        out.push('@');
+
+        match self.ip_literal {
+            Some(IpLiteral::V4(addr)) => {
+                let _ = write!(out, "[{addr}]");
+            }
+            Some(IpLiteral::V6 { addr, zone_id }) => {
+                let _ = write!(out, "[IPv6:{addr}");
+                if let Some(zone_id) = zone_id {
+                    out.push('%');
+                    out.push_str(zone_id);
+                }
+                out.push(']');
+            }
+            None => match idn::to_ascii(self.domain()) {
+                Ok(domain) => out.push_str(domain.as_str()),
+                Err(_) => out.push_str(&self.domain().to_ascii_lowercase()),
+            },
+        }
+        out
+    }
+}
+
+impl fmt::Display for EmailAddress<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.address)
+    }
+}
+
+impl AsRef<str> for EmailAddress<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.address
+    }
+}
@@ -0,0 +1,143 @@
+use crate::error::MailboxError;
+use crate::mailbox::{decode_phrase, Cursor, Mailbox};
+use crate::policy::ValidationPolicy;
+use alloc::borrow::Cow;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AddressListEntry<'a> {
+    group: Option<Cow<'a, str>>,
+    mailbox: Mailbox<'a>,
+}
+
+impl<'a> AddressListEntry<'a> {
+    #[inline]
+    #[must_use]
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn mailbox(&self) -> &Mailbox<'a> {
+        &self.mailbox
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_mailbox(self) -> Mailbox<'a> {
+        self.mailbox
+    }
+}
+
+/// Iterator over the mailboxes of an RFC 5322 `address-list`, such as the
+/// value of a `To:` or `Cc:` header.
+///
+/// Members of a group are yielded individually and tagged with the group's
+/// display name. Iteration stops after the first error.
+pub struct AddressList<'a> {
+    cursor: Cursor<'a>,
+    policy: ValidationPolicy,
+    group: Option<Cow<'a, str>>,
+    done: bool,
+}
+
+impl<'a> AddressList<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(input: &'a str) -> Self {
+        Self::with_policy(input, ValidationPolicy::DEFAULT)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_policy(input: &'a str, policy: ValidationPolicy) -> Self {
+        Self {
+            cursor: Cursor::new(input),
+            policy,
+            group: None,
+            done: false,
+        }
+    }
+
+    fn next_entry(&mut self) -> Result<Option<AddressListEntry<'a>>, MailboxError> {
+        loop {
+            self.cursor.skip_cfws()?;
+
+            match self.cursor.peek() {
+                None if self.group.is_some() => return Err(MailboxError::UnterminatedGroup),
+                None => return Ok(None),
+                Some(b',') => {
+                    self.cursor.bump();
+                    continue;
+                }
+                Some(b';') if self.group.is_some() => {
+                    self.cursor.bump();
+                    self.group = None;
+                    self.finish_entry()?;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let (end, delimiter) = self.cursor.scan_delimiter()?;
+            if delimiter == Some(b':') {
+                if self.group.is_some() {
+                    return Err(MailboxError::NestedGroup);
+                }
+
+                let name = decode_phrase(self.cursor.slice_to(end))?;
+                self.group = Some(name.map_or(Cow::Borrowed(""), |phrase| phrase.text));
+                self.cursor.advance_to(end + 1);
+                continue;
+            }
+
+            let mailbox = self.cursor.parse_mailbox(end, delimiter, self.policy)?;
+            self.finish_entry()?;
+
+            return Ok(Some(AddressListEntry {
+                group: self.group.clone(),
+                mailbox,
+            }));
+        }
+    }
+
+    fn finish_entry(&mut self) -> Result<(), MailboxError> {
+        self.cursor.skip_cfws()?;
+
+        match self.cursor.peek() {
+            None | Some(b',') => Ok(()),
+            Some(b';') if self.group.is_some() => Ok(()),
+            Some(_) => Err(MailboxError::UnexpectedCharacter),
+        }
+    }
+}
+
+impl<'a> Iterator for AddressList<'a> {
+    type Item = Result<AddressListEntry<'a>, MailboxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_entry() {
+            Ok(Some(entry)) => Some(Ok(entry)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl core::iter::FusedIterator for AddressList<'_> {}
+
+#[inline]
+#[must_use]
+pub const fn parse_address_list(input: &str) -> AddressList<'_> {
+    AddressList::new(input)
+}
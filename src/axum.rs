@@ -0,0 +1,109 @@
+//! Axum extractors, enabled with the `axum` feature.
+//!
+//! [`EmailQuery`] and [`EmailPath`] deserialize exactly like axum's `Query`
+//! and `Path`, then run [`ValidateEmails`] on the result. Malformed input is
+//! rejected as those extractors would reject it; invalid addresses get
+//! `422 Unprocessable Entity` with a JSON body naming each failing field and
+//! its [`EmailValidationError::code`](crate::EmailValidationError::code).
+//! Invalid [`Email`](crate::Email) values, which fail deserialization, are
+//! reported the same way under the name of the parameter they came from.
+
+use crate::error::{EmailFieldErrors, EmailValidationError};
+use crate::serde::{collect_rejected, rejected_fields};
+use crate::traits::ValidateEmails;
+use ::axum::extract::{FromRequestParts, Path, Query, RawPathParams};
+use ::axum::http::request::Parts;
+use ::axum::http::{header, StatusCode};
+use ::axum::response::{IntoResponse, Response};
+use ::serde::de::DeserializeOwned;
+use std::future::{poll_fn, Future};
+use std::pin::pin;
+
+impl IntoResponse for EmailFieldErrors {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            [(header::CONTENT_TYPE, "application/json")],
+            self.to_json(),
+        )
+            .into_response()
+    }
+}
+
+/// Reports addresses rejected while deserializing as field errors, falling
+/// back to the extractor's own `rejection` when there were none.
+#[cold]
+#[inline(never)]
+fn rejection_response(
+    rejection: impl IntoResponse,
+    rejected: Vec<(String, EmailValidationError)>,
+    params: &[(&str, &str)],
+) -> Response {
+    match rejected_fields(rejected, params) {
+        Err(errors) => errors.into_response(),
+        Ok(()) => rejection.into_response(),
+    }
+}
+
+/// Query string parameters with validated address fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmailQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for EmailQuery<T>
+where
+    T: DeserializeOwned + ValidateEmails,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let mut rejected = Vec::new();
+        let query = collect_rejected(&mut rejected, || Query::<T>::try_from_uri(&parts.uri));
+        let Query(value) = query.map_err(|rejection| {
+            let params = Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+                .map(|Query(params)| params)
+                .unwrap_or_default();
+            let params: Vec<_> = params
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect();
+            rejection_response(rejection, rejected, &params)
+        })?;
+        value
+            .validate_emails()
+            .map_err(IntoResponse::into_response)?;
+        Ok(Self(value))
+    }
+}
+
+/// Path parameters with validated address fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmailPath<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for EmailPath<T>
+where
+    T: DeserializeOwned + ValidateEmails + Send,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let mut rejected = Vec::new();
+        let path = {
+            let mut path = pin!(Path::<T>::from_request_parts(parts, state));
+            poll_fn(|cx| collect_rejected(&mut rejected, || path.as_mut().poll(cx))).await
+        };
+        let Path(value) = match path {
+            Ok(path) => path,
+            Err(rejection) => {
+                let params = RawPathParams::from_request_parts(parts, state).await;
+                let params: Vec<_> = params.iter().flatten().collect();
+                return Err(rejection_response(rejection, rejected, &params));
+            }
+        };
+        value
+            .validate_emails()
+            .map_err(IntoResponse::into_response)?;
+        Ok(Self(value))
+    }
+}
@@ -0,0 +1,68 @@
+//! `clap` argument parsing for [`Email`], enabled with the `clap` feature.
+//!
+//! With the feature on, `Email` arguments work out of the box through
+//! `value_parser!`; use [`EmailValueParser::with_policy`] to validate under a
+//! different [`ValidationPolicy`]. Rejections name the argument and include
+//! the [`EmailValidationError::code`](crate::EmailValidationError::code).
+
+use crate::email::Email;
+use crate::policy::ValidationPolicy;
+use ::clap::builder::{TypedValueParser, ValueParserFactory};
+use ::clap::error::{Error, ErrorKind};
+use ::clap::{Arg, Command};
+use std::ffi::OsStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EmailValueParser {
+    policy: ValidationPolicy,
+}
+
+impl EmailValueParser {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_policy(ValidationPolicy::DEFAULT)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_policy(policy: ValidationPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl TypedValueParser for EmailValueParser {
+    type Value = Email;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let Some(value) = value.to_str() else {
+            return Err(Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd));
+        };
+
+        Email::parse_with_policy(value, self.policy).map_err(|err| {
+            let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+            Error::raw(
+                ErrorKind::ValueValidation,
+                format!(
+                    "invalid email address '{value}' for '{arg}': {err} [{}]\n",
+                    err.code()
+                ),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for Email {
+    type Parser = EmailValueParser;
+
+    #[inline]
+    fn value_parser() -> Self::Parser {
+        EmailValueParser::new()
+    }
+}
@@ -3,5 +3,11 @@ pub const MAX_DOMAIN_LENGTH: usize = 255;
 pub const MAX_LABEL_LENGTH: usize = 63;
 pub const MIN_EMAIL_LENGTH: usize = 3;
 pub const MAX_EMAIL_LENGTH: usize = 320;
+pub const IPV6_TAG: &[u8] = b"IPv6:";
+pub const MAX_IP_LENGTH: usize = 45;
+pub const MAX_IP_ZONE_ID_LENGTH: usize = 32;
+pub const MAX_IP_LITERAL_LENGTH: usize =
+    2 + IPV6_TAG.len() + MAX_IP_LENGTH + 1 + MAX_IP_ZONE_ID_LENGTH;
 pub const SIMD_CHUNK_SIZE: usize = 32;
-pub const ASCII_MASK: u64 = 0x8080808080808080;
+pub const ASCII_MASK: u64 = 0x8080_8080_8080_8080;
+pub const MAX_SMTP_PATH_LENGTH: usize = 256;
@@ -0,0 +1,191 @@
+//! CSV column validation, enabled with the `csv` feature.
+
+use crate::error::{CsvError, EmailValidationError};
+use crate::policy::ValidationPolicy;
+use crate::validator::EmailValidator;
+use ::csv::{ByteRecord, ReaderBuilder, Writer, WriterBuilder};
+use std::io::{Read, Write};
+
+/// Header of the column appended to rejected rows.
+pub const REASON_HEADER: &str = "reason";
+
+/// The column holding the address, by header name or zero-based index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CsvColumn {
+    Name(String),
+    Index(usize),
+}
+
+impl From<&str> for CsvColumn {
+    #[inline]
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_owned())
+    }
+}
+
+impl From<String> for CsvColumn {
+    #[inline]
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<usize> for CsvColumn {
+    #[inline]
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CsvReport {
+    valid: usize,
+    invalid: usize,
+}
+
+impl CsvReport {
+    #[inline]
+    #[must_use]
+    pub const fn valid(&self) -> usize {
+        self.valid
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn invalid(&self) -> usize {
+        self.invalid
+    }
+
+    /// Data rows processed, not counting the header.
+    #[inline]
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        self.valid + self.invalid
+    }
+}
+
+/// Splits CSV rows by whether one column holds a valid address.
+///
+/// Valid rows are copied unchanged. Invalid rows gain a trailing
+/// [`REASON_HEADER`] column holding the [`EmailValidationError::code`], with
+/// rows too short to have the column reported as `empty`. Surrounding ASCII
+/// whitespace in the address field is ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvValidator {
+    column: CsvColumn,
+    has_headers: bool,
+    delimiter: u8,
+    policy: ValidationPolicy,
+}
+
+impl CsvValidator {
+    #[must_use]
+    pub fn new(column: impl Into<CsvColumn>) -> Self {
+        Self {
+            column: column.into(),
+            has_headers: true,
+            delimiter: b',',
+            policy: ValidationPolicy::DEFAULT,
+        }
+    }
+
+    /// Whether the first row is a header; defaults to `true`. Columns can only
+    /// be selected by name when it is.
+    #[inline]
+    #[must_use]
+    pub const fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn policy(mut self, policy: ValidationPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn run<R: Read, V: Write, I: Write>(
+        &self,
+        input: R,
+        valid: V,
+        invalid: I,
+    ) -> Result<CsvReport, CsvError> {
+        let mut reader = ReaderBuilder::new()
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_reader(input);
+        let mut valid = self.writer(valid);
+        let mut invalid = self.writer(invalid);
+
+        let column = if self.has_headers {
+            let mut headers = reader.byte_headers()?.clone();
+            let column = self.resolve_column(&headers)?;
+            valid.write_byte_record(&headers)?;
+            headers.push_field(REASON_HEADER.as_bytes());
+            invalid.write_byte_record(&headers)?;
+            column
+        } else {
+            match &self.column {
+                CsvColumn::Index(index) => *index,
+                CsvColumn::Name(name) => return Err(CsvError::ColumnNotFound(name.clone())),
+            }
+        };
+
+        let mut report = CsvReport::default();
+        let mut record = ByteRecord::new();
+        while reader.read_byte_record(&mut record)? {
+            let result = record
+                .get(column)
+                .map_or(Err(EmailValidationError::Empty), |field| {
+                    EmailValidator::parse_with_policy(field.trim_ascii(), self.policy).map(|_| ())
+                });
+
+            match result {
+                Ok(()) => {
+                    valid.write_byte_record(&record)?;
+                    report.valid += 1;
+                }
+                Err(err) => {
+                    record.push_field(err.code().as_bytes());
+                    invalid.write_byte_record(&record)?;
+                    report.invalid += 1;
+                }
+            }
+        }
+
+        valid.flush().map_err(::csv::Error::from)?;
+        invalid.flush().map_err(::csv::Error::from)?;
+        Ok(report)
+    }
+
+    fn writer<W: Write>(&self, output: W) -> Writer<W> {
+        WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_writer(output)
+    }
+
+    fn resolve_column(&self, headers: &ByteRecord) -> Result<usize, CsvError> {
+        match &self.column {
+            CsvColumn::Index(index) if *index < headers.len() => Ok(*index),
+            CsvColumn::Index(index) => Err(CsvError::ColumnNotFound(index.to_string())),
+            CsvColumn::Name(name) => headers
+                .iter()
+                .position(|header| {
+                    header
+                        .trim_ascii()
+                        .eq_ignore_ascii_case(name.trim().as_bytes())
+                })
+                .ok_or_else(|| CsvError::ColumnNotFound(name.clone())),
+        }
+    }
+}
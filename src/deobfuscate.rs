@@ -0,0 +1,297 @@
+use crate::extract::EmailExtractor;
+use crate::policy::ValidationPolicy;
+use alloc::borrow::{Cow, ToOwned};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Words standing in for `@`, lowercase. Covers English, Spanish/Portuguese,
+/// French, Italian, German, Dutch, Swedish, Polish and Russian.
+const AT_WORDS: &[&str] = &[
+    "at",
+    "arroba",
+    "arobase",
+    "chiocciola",
+    "klammeraffe",
+    "apenstaartje",
+    "snabel-a",
+    "małpa",
+    "malpa",
+    "собака",
+];
+
+/// Words standing in for `.`, lowercase.
+const DOT_WORDS: &[&str] = &[
+    "dot",
+    "punto",
+    "ponto",
+    "point",
+    "punkt",
+    "punt",
+    "kropka",
+    "piste",
+    "точка",
+];
+
+/// Longest substitution word in bytes, bounding the scan for one.
+const MAX_WORD_LENGTH: usize = 16;
+
+const FULLWIDTH_AT: &str = "\u{FF20}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    At,
+    Dot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Substitution {
+    At,
+    /// An unbracketed ` at `, which is only trusted when the same address
+    /// also has a bracketed or entity dot.
+    BareAt,
+    Dot,
+    /// An unbracketed ` dot ` or ` . `, which cannot vouch for a bare ` at `.
+    BareDot,
+}
+
+#[inline(always)]
+const fn closing_bracket(open: u8) -> Option<u8> {
+    match open {
+        b'[' => Some(b']'),
+        b'(' => Some(b')'),
+        b'{' => Some(b'}'),
+        b'<' => Some(b'>'),
+        _ => None,
+    }
+}
+
+fn skip_whitespace(input: &[u8], mut pos: usize) -> usize {
+    while input.get(pos).is_some_and(u8::is_ascii_whitespace) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Matches a substitution word or a bare `@`/`.` symbol starting at `pos`.
+fn match_word(input: &[u8], pos: usize) -> Option<(Token, usize)> {
+    match input.get(pos)? {
+        b'@' => return Some((Token::At, pos + 1)),
+        b'.' => return Some((Token::Dot, pos + 1)),
+        _ => {}
+    }
+
+    let rest = &input[pos..input.len().min(pos + MAX_WORD_LENGTH)];
+    let len = rest
+        .iter()
+        .position(|&b| b.is_ascii_whitespace() || (b.is_ascii_punctuation() && b != b'-'))
+        .unwrap_or(rest.len());
+
+    let word = core::str::from_utf8(&rest[..len]).ok()?.to_lowercase();
+    let token = if AT_WORDS.contains(&word.as_str()) {
+        Token::At
+    } else if DOT_WORDS.contains(&word.as_str()) {
+        Token::Dot
+    } else {
+        return None;
+    };
+
+    Some((token, pos + len))
+}
+
+/// `[at]`, `( dot )`, `{@}` and similar, with any surrounding whitespace.
+fn match_bracketed(input: &[u8], pos: usize) -> Option<(Token, usize)> {
+    let open = skip_whitespace(input, pos);
+    let close = closing_bracket(*input.get(open)?)?;
+
+    let (token, end) = match_word(input, skip_whitespace(input, open + 1))?;
+    let end = skip_whitespace(input, end);
+    (input.get(end) == Some(&close)).then(|| (token, skip_whitespace(input, end + 1)))
+}
+
+/// ` at `, ` dot `, ` @ ` or ` . ` delimited by whitespace on both sides.
+/// Also reports whether the match is ambiguous in prose: any dot, or an `@`
+/// spelled as a word.
+fn match_bare(input: &[u8], pos: usize) -> Option<(Token, bool, usize)> {
+    let start = skip_whitespace(input, pos);
+    if start == pos {
+        return None;
+    }
+
+    let (token, end) = match_word(input, start)?;
+    if !input.get(end).is_some_and(u8::is_ascii_whitespace) {
+        return None;
+    }
+
+    let ambiguous = token == Token::Dot || end - start > 1;
+    Some((token, ambiguous, skip_whitespace(input, end)))
+}
+
+/// `&#64;`, `&#x40;`, `&commat;` and their `.` counterparts.
+fn match_entity(input: &[u8], pos: usize) -> Option<(Token, usize)> {
+    let rest = input.get(pos + 1..)?;
+    let semicolon = rest.iter().take(10).position(|&b| b == b';')?;
+    let name = &rest[..semicolon];
+
+    let value = match name {
+        [b'#', b'x' | b'X', hex @ ..] => core::str::from_utf8(hex)
+            .ok()
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok()),
+        [b'#', decimal @ ..] => core::str::from_utf8(decimal)
+            .ok()
+            .and_then(|decimal| decimal.parse().ok()),
+        b"commat" => Some(0x40),
+        b"period" => Some(0x2E),
+        _ => None,
+    };
+
+    let token = match value? {
+        0x40 => Token::At,
+        0x2E => Token::Dot,
+        _ => return None,
+    };
+
+    Some((token, pos + 1 + semicolon + 1))
+}
+
+/// The input with every recognized substitution replaced by `@` or `.`,
+/// plus the original byte range behind each normalized byte.
+struct Normalized {
+    bytes: Vec<u8>,
+    origins: Vec<Range<usize>>,
+    substitutions: Vec<(usize, Substitution)>,
+}
+
+impl Normalized {
+    fn new(input: &[u8]) -> Self {
+        let mut normalized = Self {
+            bytes: Vec::with_capacity(input.len()),
+            origins: Vec::with_capacity(input.len()),
+            substitutions: Vec::new(),
+        };
+
+        let mut pos = 0;
+        while pos < input.len() {
+            let byte = input[pos];
+            let matched = match byte {
+                b'&' => match_entity(input, pos).map(|(token, end)| (token, false, end)),
+                b'[' | b'(' | b'{' | b'<' => {
+                    match_bracketed(input, pos).map(|(token, end)| (token, false, end))
+                }
+                _ if byte.is_ascii_whitespace() => match_bracketed(input, pos)
+                    .map(|(token, end)| (token, false, end))
+                    .or_else(|| match_bare(input, pos)),
+                _ if input[pos..].starts_with(FULLWIDTH_AT.as_bytes()) => {
+                    Some((Token::At, false, pos + FULLWIDTH_AT.len()))
+                }
+                _ => None,
+            };
+
+            if let Some((token, bare, end)) = matched {
+                let (replacement, substitution) = match (token, bare) {
+                    (Token::At, false) => (b'@', Substitution::At),
+                    (Token::At, true) => (b'@', Substitution::BareAt),
+                    (Token::Dot, false) => (b'.', Substitution::Dot),
+                    (Token::Dot, true) => (b'.', Substitution::BareDot),
+                };
+                normalized
+                    .substitutions
+                    .push((normalized.bytes.len(), substitution));
+                normalized.push(replacement, pos..end);
+                pos = end;
+            } else if byte.is_ascii_whitespace() {
+                // Copy the whole run so a substitution never starts mid-run.
+                let end = skip_whitespace(input, pos);
+                for (offset, &space) in (pos..end).zip(&input[pos..end]) {
+                    normalized.push(space, offset..offset + 1);
+                }
+                pos = end;
+            } else {
+                normalized.push(byte, pos..pos + 1);
+                pos += 1;
+            }
+        }
+
+        normalized
+    }
+
+    fn push(&mut self, byte: u8, origin: Range<usize>) {
+        self.bytes.push(byte);
+        self.origins.push(origin);
+    }
+
+    fn substitutions_in(&self, range: &Range<usize>) -> &[(usize, Substitution)] {
+        let start = self
+            .substitutions
+            .partition_point(|&(pos, _)| pos < range.start);
+        let end = self
+            .substitutions
+            .partition_point(|&(pos, _)| pos < range.end);
+        &self.substitutions[start..end]
+    }
+}
+
+/// Extractor that additionally recovers obfuscated addresses such as
+/// `john [at] example [dot] com`, `john(at)example.com` or `john&#64;example.com`.
+///
+/// Yields the span of the original text together with the address. The
+/// address is borrowed when it appears verbatim and owned when it had to be
+/// reconstructed. A bare ` at ` is only accepted when the domain also has a
+/// bracketed or entity dot, so prose like `look at example dot com` is not
+/// reported.
+#[derive(Debug, Clone)]
+pub struct DeobfuscatingExtractor<'a> {
+    matches: alloc::vec::IntoIter<(Range<usize>, Cow<'a, str>)>,
+}
+
+impl<'a> DeobfuscatingExtractor<'a> {
+    pub(crate) fn new(input: &'a [u8], policy: ValidationPolicy) -> Self {
+        let normalized = Normalized::new(input);
+
+        let matches = EmailExtractor::with_policy(&normalized.bytes, policy)
+            .filter_map(|(range, address)| {
+                let substitutions = normalized.substitutions_in(&range);
+                let has_bare_at = substitutions
+                    .iter()
+                    .any(|&(_, sub)| sub == Substitution::BareAt);
+                let has_marked_dot = substitutions
+                    .iter()
+                    .any(|&(_, sub)| sub == Substitution::Dot);
+                if has_bare_at && !has_marked_dot {
+                    return None;
+                }
+
+                let span =
+                    normalized.origins[range.start].start..normalized.origins[range.end - 1].end;
+                let address = if substitutions.is_empty() {
+                    core::str::from_utf8(&input[span.clone()])
+                        .map_or_else(|_| Cow::Owned(address.as_str().to_owned()), Cow::Borrowed)
+                } else {
+                    Cow::Owned(address.as_str().to_owned())
+                };
+
+                Some((span, address))
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        Self { matches }
+    }
+}
+
+impl<'a> Iterator for DeobfuscatingExtractor<'a> {
+    type Item = (Range<usize>, Cow<'a, str>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.matches.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.matches.size_hint()
+    }
+}
+
+impl ExactSizeIterator for DeobfuscatingExtractor<'_> {}
+
+impl core::iter::FusedIterator for DeobfuscatingExtractor<'_> {}
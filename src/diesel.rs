@@ -0,0 +1,31 @@
+//! Diesel support for [`Email`], enabled with the `diesel` feature.
+//!
+//! [`Email`] maps to `Text` columns on every backend, and loading fails when
+//! the stored value is not a valid address.
+
+use crate::email::Email;
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::serialize::{self, Output, ToSql};
+use ::diesel::sql_types::Text;
+
+impl<DB> ToSql<Text, DB> for Email
+where
+    DB: Backend,
+    str: ToSql<Text, DB>,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.as_str().to_sql(out)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for Email
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::try_from(String::from_sql(bytes)?)?)
+    }
+}
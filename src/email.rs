@@ -0,0 +1,188 @@
+use crate::address::EmailAddress;
+use crate::error::{EmailFieldErrors, EmailValidationError};
+use crate::ip::parse_ip_literal;
+use crate::policy::ValidationPolicy;
+use crate::traits::{validate_address, ValidateEmail, ValidateEmails};
+use crate::validator::EmailValidator;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+
+/// An owned, validated address; the owned counterpart of [`EmailAddress`].
+///
+/// An `Email` can only be built from input that passed validation, so holding
+/// one is proof the address is well formed.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct Email {
+    pub(crate) address: String,
+    at_pos: usize,
+    ip_literal: bool,
+}
+
+impl Email {
+    #[inline]
+    pub fn parse(email: &str) -> Result<Self, EmailValidationError> {
+        Self::parse_with_policy(email, ValidationPolicy::DEFAULT)
+    }
+
+    #[inline]
+    pub fn parse_with_policy(
+        email: &str,
+        policy: ValidationPolicy,
+    ) -> Result<Self, EmailValidationError> {
+        EmailValidator::parse_with_policy(email.as_bytes(), policy).map(Self::from)
+    }
+
+    /// Validates `email` under the default policy, reusing its allocation and
+    /// handing it back on failure.
+    pub(crate) fn parse_owned(email: String) -> Result<Self, (String, EmailValidationError)> {
+        let (at_pos, ip_literal) = match EmailValidator::parse_str(&email) {
+            Ok(address) => (address.local_part().len(), address.is_ip_literal()),
+            Err(err) => return Err((email, err)),
+        };
+        Ok(Self {
+            address: email,
+            at_pos,
+            ip_literal,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.address
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn local_part(&self) -> &str {
+        &self.address[..self.at_pos]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn domain(&self) -> &str {
+        &self.address[self.at_pos + 1..]
+    }
+
+    /// Borrows the address as an [`EmailAddress`], re-parsing the domain only
+    /// when it is an IP literal.
+    #[must_use]
+    pub fn as_address(&self) -> EmailAddress<'_> {
+        let ip_literal = if self.ip_literal {
+            // The literal already passed validation under the policy this
+            // value was built with; only the zone identifier check depends on it.
+            let policy = ValidationPolicy::DEFAULT.allow_ip_zone_id(true);
+            parse_ip_literal(self.domain().as_bytes(), policy).ok()
+        } else {
+            None
+        };
+        EmailAddress::new(&self.address, self.at_pos, ip_literal)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.address
+    }
+}
+
+impl From<EmailAddress<'_>> for Email {
+    #[inline]
+    fn from(address: EmailAddress<'_>) -> Self {
+        Self {
+            address: address.as_str().to_owned(),
+            at_pos: address.local_part().len(),
+            ip_literal: address.is_ip_literal(),
+        }
+    }
+}
+
+impl From<Email> for String {
+    #[inline]
+    fn from(email: Email) -> Self {
+        email.address
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = EmailValidationError;
+
+    /// Validates the string, reusing its allocation.
+    fn try_from(email: String) -> Result<Self, Self::Error> {
+        Self::parse_owned(email).map_err(|(_, err)| err)
+    }
+}
+
+impl TryFrom<&str> for Email {
+    type Error = EmailValidationError;
+
+    #[inline]
+    fn try_from(email: &str) -> Result<Self, Self::Error> {
+        Self::parse(email)
+    }
+}
+
+impl FromStr for Email {
+    type Err = EmailValidationError;
+
+    #[inline]
+    fn from_str(email: &str) -> Result<Self, Self::Err> {
+        Self::parse(email)
+    }
+}
+
+impl fmt::Display for Email {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.address)
+    }
+}
+
+impl AsRef<str> for Email {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.address
+    }
+}
+
+impl PartialEq<str> for Email {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.address == other
+    }
+}
+
+impl PartialEq<&str> for Email {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.address == *other
+    }
+}
+
+impl ValidateEmail for Email {
+    #[inline]
+    fn validate_email(&self) -> bool {
+        self.address.validate_email()
+    }
+
+    /// Re-checks the address, which may have been built under a more
+    /// permissive policy than `policy`.
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        self.address.check_email(policy)
+    }
+}
+
+impl ValidateEmails for Email {
+    #[inline]
+    fn validate_emails(&self) -> Result<(), EmailFieldErrors> {
+        validate_address(self)
+    }
+}
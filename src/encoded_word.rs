@@ -0,0 +1,374 @@
+use crate::error::EncodedWordError;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub const MAX_ENCODED_WORD_LENGTH: usize = 75;
+
+const UTF8_PREFIX: &str = "=?UTF-8?";
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+static WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{8D}', '\u{017D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{9D}', '\u{017E}', '\u{0178}',
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    B,
+    Q,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Charset {
+    Utf8,
+    Ascii,
+    Latin1,
+    Latin9,
+    Windows1252,
+}
+
+impl Charset {
+    fn from_label(label: &str) -> Option<Self> {
+        const LABELS: &[(&str, Charset)] = &[
+            ("utf-8", Charset::Utf8),
+            ("utf8", Charset::Utf8),
+            ("us-ascii", Charset::Ascii),
+            ("ascii", Charset::Ascii),
+            ("iso-8859-1", Charset::Latin1),
+            ("iso8859-1", Charset::Latin1),
+            ("iso_8859-1", Charset::Latin1),
+            ("latin1", Charset::Latin1),
+            ("l1", Charset::Latin1),
+            ("iso-8859-15", Charset::Latin9),
+            ("iso8859-15", Charset::Latin9),
+            ("iso_8859-15", Charset::Latin9),
+            ("latin9", Charset::Latin9),
+            ("latin-9", Charset::Latin9),
+            ("windows-1252", Charset::Windows1252),
+            ("cp1252", Charset::Windows1252),
+        ];
+
+        // RFC 2231 allows a `*language` suffix on the charset.
+        let name = label.split_once('*').map_or(label, |(name, _)| name);
+
+        LABELS
+            .iter()
+            .find(|(candidate, _)| candidate.eq_ignore_ascii_case(name))
+            .map(|&(_, charset)| charset)
+    }
+
+    fn decode(self, bytes: Vec<u8>) -> Result<String, EncodedWordError> {
+        match self {
+            Self::Utf8 => {
+                String::from_utf8(bytes).map_err(|_| EncodedWordError::InvalidCharsetData)
+            }
+            Self::Ascii if bytes.is_ascii() => {
+                String::from_utf8(bytes).map_err(|_| EncodedWordError::InvalidCharsetData)
+            }
+            Self::Ascii => Err(EncodedWordError::InvalidCharsetData),
+            Self::Latin1 => Ok(bytes.into_iter().map(char::from).collect()),
+            Self::Latin9 => Ok(bytes.into_iter().map(latin9_char).collect()),
+            Self::Windows1252 => Ok(bytes
+                .into_iter()
+                .map(|byte| match byte {
+                    0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+                    _ => char::from(byte),
+                })
+                .collect()),
+        }
+    }
+}
+
+const fn latin9_char(byte: u8) -> char {
+    match byte {
+        0xA4 => '\u{20AC}',
+        0xA6 => '\u{0160}',
+        0xA8 => '\u{0161}',
+        0xB4 => '\u{017D}',
+        0xB8 => '\u{017E}',
+        0xBC => '\u{0152}',
+        0xBD => '\u{0153}',
+        0xBE => '\u{0178}',
+        _ => byte as char,
+    }
+}
+
+#[inline(always)]
+const fn is_token_char(byte: u8) -> bool {
+    matches!(byte, b'!'..=b'~')
+        && !matches!(
+            byte,
+            b'(' | b')'
+                | b'<'
+                | b'>'
+                | b'@'
+                | b','
+                | b';'
+                | b':'
+                | b'"'
+                | b'/'
+                | b'['
+                | b']'
+                | b'?'
+                | b'.'
+                | b'='
+        )
+}
+
+#[inline(always)]
+const fn is_encoded_text_char(byte: u8) -> bool {
+    matches!(byte, b'!'..=b'~') && byte != b'?'
+}
+
+#[inline(always)]
+const fn is_q_literal(byte: u8) -> bool {
+    matches!(byte, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'!' | b'*' | b'+' | b'-' | b'/')
+}
+
+fn split_encoded_word(word: &str) -> Option<(&str, Encoding, &str)> {
+    if word.len() > MAX_ENCODED_WORD_LENGTH {
+        return None;
+    }
+
+    let inner = word.strip_prefix("=?")?.strip_suffix("?=")?;
+    let mut parts = inner.splitn(3, '?');
+    let (charset, encoding, text) = (parts.next()?, parts.next()?, parts.next()?);
+
+    if charset.is_empty()
+        || !charset.bytes().all(is_token_char)
+        || !text.bytes().all(is_encoded_text_char)
+    {
+        return None;
+    }
+
+    let encoding = match encoding {
+        "B" | "b" => Encoding::B,
+        "Q" | "q" => Encoding::Q,
+        _ => return None,
+    };
+
+    Some((charset, encoding, text))
+}
+
+/// Whether `word` is a syntactically valid RFC 2047 encoded-word, e.g.
+/// `=?UTF-8?B?5byg5LiJ?=` or `=?ISO-8859-1?Q?Andr=E9?=`.
+#[inline]
+#[must_use]
+pub fn is_encoded_word(word: &str) -> bool {
+    split_encoded_word(word).is_some()
+}
+
+const fn base64_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+const fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn decode_b(text: &str) -> Result<Vec<u8>, EncodedWordError> {
+    let data = text.trim_end_matches('=').as_bytes();
+    if data.len() % 4 == 1 {
+        return Err(EncodedWordError::InvalidEncoding);
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+
+    for &byte in data {
+        let value = base64_value(byte).ok_or(EncodedWordError::InvalidEncoding)?;
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits).to_le_bytes()[0]);
+        }
+    }
+
+    Ok(out)
+}
+
+fn decode_q(text: &str) -> Result<Vec<u8>, EncodedWordError> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'_' => out.push(b' '),
+            b'=' => {
+                let high = bytes.get(pos + 1).copied().and_then(hex_value);
+                let low = bytes.get(pos + 2).copied().and_then(hex_value);
+                let (Some(high), Some(low)) = (high, low) else {
+                    return Err(EncodedWordError::InvalidEncoding);
+                };
+                out.push((high << 4) | low);
+                pos += 2;
+            }
+            byte => out.push(byte),
+        }
+        pos += 1;
+    }
+
+    Ok(out)
+}
+
+/// Decodes a single RFC 2047 encoded-word into text.
+///
+/// Supports the `B` and `Q` encodings with UTF-8, US-ASCII, ISO-8859-1,
+/// ISO-8859-15 and Windows-1252 charsets.
+pub fn decode_word(word: &str) -> Result<String, EncodedWordError> {
+    let (charset, encoding, text) = split_encoded_word(word).ok_or(EncodedWordError::Malformed)?;
+    let charset = Charset::from_label(charset).ok_or(EncodedWordError::UnsupportedCharset)?;
+
+    let bytes = match encoding {
+        Encoding::B => decode_b(text)?,
+        Encoding::Q => decode_q(text)?,
+    };
+
+    charset.decode(bytes)
+}
+
+/// Whether `text` must be encoded before it can appear in a header phrase.
+#[inline]
+#[must_use]
+pub fn needs_encoding(text: &str) -> bool {
+    text.bytes()
+        .any(|b| !matches!(b, b' '..=b'~') && b != b'\t')
+}
+
+fn push_b(out: &mut String, chunk: &[u8]) {
+    for group in chunk.chunks(3) {
+        let buffer = group.iter().enumerate().fold(0u32, |acc, (i, &byte)| {
+            acc | (u32::from(byte) << (16 - 8 * i))
+        });
+
+        for i in 0..4 {
+            if i <= group.len() {
+                let index = (buffer >> (18 - 6 * i)) & 0x3F;
+                out.push(char::from(BASE64_ALPHABET[index as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+}
+
+fn push_q(out: &mut String, chunk: &[u8]) {
+    for &byte in chunk {
+        if byte == b' ' {
+            out.push('_');
+        } else if q_len(byte) == 1 {
+            out.push(char::from(byte));
+        } else {
+            out.push('=');
+            out.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+            out.push(char::from(HEX_DIGITS[usize::from(byte & 0x0F)]));
+        }
+    }
+}
+
+const fn q_len(byte: u8) -> usize {
+    if byte == b' ' || is_q_literal(byte) {
+        1
+    } else {
+        3
+    }
+}
+
+fn word_end(text: &str, encoding: Encoding, budget: usize) -> usize {
+    let mut end = 0;
+    let mut q_encoded = 0;
+
+    for (index, c) in text.char_indices() {
+        let next = index + c.len_utf8();
+        let fits = match encoding {
+            Encoding::B => next.div_ceil(3) * 4 <= budget,
+            Encoding::Q => {
+                q_encoded += text.as_bytes()[index..next]
+                    .iter()
+                    .copied()
+                    .map(q_len)
+                    .sum::<usize>();
+                q_encoded <= budget
+            }
+        };
+
+        if !fits {
+            break;
+        }
+        end = next;
+    }
+
+    end
+}
+
+/// Encodes `text` as one or more space-separated UTF-8 encoded-words, each
+/// no longer than [`MAX_ENCODED_WORD_LENGTH`] and never splitting a character.
+#[must_use]
+pub fn encode(text: &str, encoding: Encoding) -> String {
+    let suffix = match encoding {
+        Encoding::B => "B?",
+        Encoding::Q => "Q?",
+    };
+    let budget = MAX_ENCODED_WORD_LENGTH - UTF8_PREFIX.len() - suffix.len() - 2;
+
+    let mut out = String::with_capacity(text.len() * 2 + UTF8_PREFIX.len() + 4);
+    let mut rest = text;
+
+    loop {
+        let end = word_end(rest, encoding, budget);
+
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(UTF8_PREFIX);
+        out.push_str(suffix);
+        match encoding {
+            Encoding::B => push_b(&mut out, &rest.as_bytes()[..end]),
+            Encoding::Q => push_q(&mut out, &rest.as_bytes()[..end]),
+        }
+        out.push_str("?=");
+
+        rest = &rest[end..];
+        if rest.is_empty() {
+            return out;
+        }
+    }
+}
+
+/// Returns `text` unchanged when it is header-safe printable ASCII and
+/// encodes it otherwise, preferring `Q` for mostly-ASCII text.
+#[must_use]
+pub fn encode_if_needed(text: &str) -> Cow<'_, str> {
+    if !needs_encoding(text) {
+        return Cow::Borrowed(text);
+    }
+
+    let non_ascii = text.bytes().filter(|b| !b.is_ascii()).count();
+    let encoding = if non_ascii * 3 <= text.len() {
+        Encoding::Q
+    } else {
+        Encoding::B
+    };
+
+    Cow::Owned(encode(text, encoding))
+}
@@ -1,3 +1,7 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum EmailValidationError {
@@ -10,10 +14,12 @@ pub enum EmailValidationError {
     InvalidDomainPart = 6,
     InvalidIpLiteral = 7,
     IdnProcessingFailed = 8,
+    IpZoneIdNotAllowed = 9,
 }
 
 impl EmailValidationError {
     #[inline]
+    #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
             Self::Empty => "Email is empty",
@@ -25,6 +31,390 @@ impl EmailValidationError {
             Self::InvalidDomainPart => "Invalid domain part",
             Self::InvalidIpLiteral => "Invalid IP literal",
             Self::IdnProcessingFailed => "IDN processing failed",
+            Self::IpZoneIdNotAllowed => "IPv6 zone identifiers are not allowed",
+        }
+    }
+
+    /// Stable `snake_case` identifier for the error kind, suitable for reports
+    /// and machine-readable output.
+    #[inline]
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::TooShort => "too_short",
+            Self::TooLong => "too_long",
+            Self::NoAtSymbol => "no_at_symbol",
+            Self::MultipleAtSymbols => "multiple_at_symbols",
+            Self::InvalidUserPart => "invalid_user_part",
+            Self::InvalidDomainPart => "invalid_domain_part",
+            Self::InvalidIpLiteral => "invalid_ip_literal",
+            Self::IdnProcessingFailed => "idn_processing_failed",
+            Self::IpZoneIdNotAllowed => "ip_zone_id_not_allowed",
+        }
+    }
+}
+
+impl fmt::Display for EmailValidationError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for EmailValidationError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MailboxError {
+    Empty,
+    InvalidDisplayName,
+    UnterminatedQuotedString,
+    UnterminatedComment,
+    UnterminatedAngleAddr,
+    UnterminatedGroup,
+    NestedGroup,
+    UnexpectedCharacter,
+    InvalidAddress(EmailValidationError),
+}
+
+impl MailboxError {
+    #[inline]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Empty => "Mailbox is empty",
+            Self::InvalidDisplayName => "Invalid display name",
+            Self::UnterminatedQuotedString => "Unterminated quoted string",
+            Self::UnterminatedComment => "Unterminated comment",
+            Self::UnterminatedAngleAddr => "Missing closing '>' in angle address",
+            Self::UnterminatedGroup => "Missing closing ';' in group",
+            Self::NestedGroup => "Groups cannot be nested",
+            Self::UnexpectedCharacter => "Unexpected character after mailbox",
+            Self::InvalidAddress(err) => err.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for MailboxError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for MailboxError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidAddress(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<EmailValidationError> for MailboxError {
+    #[inline]
+    fn from(err: EmailValidationError) -> Self {
+        Self::InvalidAddress(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum EncodedWordError {
+    Malformed = 0,
+    UnsupportedCharset = 1,
+    InvalidEncoding = 2,
+    InvalidCharsetData = 3,
+}
+
+impl EncodedWordError {
+    #[inline]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Malformed => "Malformed encoded-word",
+            Self::UnsupportedCharset => "Unsupported encoded-word charset",
+            Self::InvalidEncoding => "Invalid B or Q encoded text",
+            Self::InvalidCharsetData => "Decoded bytes are invalid for the charset",
+        }
+    }
+}
+
+impl fmt::Display for EncodedWordError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for EncodedWordError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmtpPathError {
+    UnknownCommand,
+    MissingAngleBrackets,
+    TooLong,
+    NullPathNotAllowed,
+    InvalidSourceRoute,
+    SourceRouteNotAllowed,
+    InvalidParameter,
+    InvalidAddress(EmailValidationError),
+}
+
+impl SmtpPathError {
+    #[inline]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::UnknownCommand => "Expected MAIL FROM: or RCPT TO:",
+            Self::MissingAngleBrackets => "Path must be enclosed in angle brackets",
+            Self::TooLong => "Path exceeds 256 octets",
+            Self::NullPathNotAllowed => "Null path is only allowed as a reverse-path",
+            Self::InvalidSourceRoute => "Invalid source route",
+            Self::SourceRouteNotAllowed => "Source routes are not allowed",
+            Self::InvalidParameter => "Invalid ESMTP parameter",
+            Self::InvalidAddress(err) => err.as_str(),
         }
     }
 }
+
+impl fmt::Display for SmtpPathError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for SmtpPathError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidAddress(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<EmailValidationError> for SmtpPathError {
+    #[inline]
+    fn from(err: EmailValidationError) -> Self {
+        Self::InvalidAddress(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MailtoError {
+    MissingScheme,
+    InvalidPercentEncoding,
+    InvalidUtf8,
+    InvalidHeader,
+    InvalidAddress(EmailValidationError),
+}
+
+impl MailtoError {
+    #[inline]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::MissingScheme => "URI does not start with mailto:",
+            Self::InvalidPercentEncoding => "Invalid percent-encoding",
+            Self::InvalidUtf8 => "Percent-decoded text is not valid UTF-8",
+            Self::InvalidHeader => "Invalid header field in mailto: URI",
+            Self::InvalidAddress(err) => err.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for MailtoError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for MailtoError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidAddress(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<EmailValidationError> for MailtoError {
+    #[inline]
+    fn from(err: EmailValidationError) -> Self {
+        Self::InvalidAddress(err)
+    }
+}
+
+/// An invalid address found in a struct field, tagged with the field's path
+/// such as `email` or `contacts[2]`.
+///
+/// The path is empty when the value checked was the address itself rather
+/// than a struct holding it.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailFieldError {
+    pub(crate) path: String,
+    error: EmailValidationError,
+}
+
+#[cfg(feature = "alloc")]
+impl EmailFieldError {
+    #[inline]
+    #[must_use]
+    pub fn new(path: impl Into<String>, error: EmailValidationError) -> Self {
+        Self {
+            path: path.into(),
+            error,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> EmailValidationError {
+        self.error
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for EmailFieldError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            fmt::Display::fmt(&self.error, f)
+        } else {
+            write!(f, "{}: {}", self.path, self.error)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for EmailFieldError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Every invalid address found by [`ValidateEmails`](crate::ValidateEmails),
+/// in field order. Never empty.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailFieldErrors(Vec<EmailFieldError>);
+
+#[cfg(feature = "alloc")]
+impl EmailFieldErrors {
+    /// Returns `Ok(())` when no errors were collected.
+    #[inline]
+    pub fn check(errors: Vec<EmailFieldError>) -> Result<(), Self> {
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Self(errors))
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn errors(&self) -> &[EmailFieldError] {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_vec(self) -> Vec<EmailFieldError> {
+        self.0
+    }
+
+    /// Renders the errors as the JSON body web extractors reject with:
+    /// `{"errors":[{"field":"email","code":"no_at_symbol","message":"..."}]}`.
+    #[cfg(any(feature = "axum", feature = "actix-web"))]
+    pub(crate) fn to_json(&self) -> String {
+        use core::fmt::Write;
+
+        let mut json = String::from("{\"errors\":[");
+        for (i, err) in self.0.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"field\":");
+            crate::json::push_json_string(&mut json, &err.path);
+            let _ = write!(
+                json,
+                ",\"code\":\"{}\",\"message\":\"{}\"}}",
+                err.error.code(),
+                err.error.as_str()
+            );
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for EmailFieldErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, err) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            fmt::Display::fmt(err, f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for EmailFieldErrors {}
+
+#[cfg(feature = "alloc")]
+impl IntoIterator for EmailFieldErrors {
+    type Item = EmailFieldError;
+    type IntoIter = alloc::vec::IntoIter<EmailFieldError>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub enum CsvError {
+    Csv(::csv::Error),
+    ColumnNotFound(String),
+}
+
+#[cfg(feature = "csv")]
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Csv(err) => write!(f, "CSV error: {err}"),
+            Self::ColumnNotFound(column) => write!(f, "Column `{column}` not found"),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl core::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Csv(err) => Some(err),
+            Self::ColumnNotFound(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<::csv::Error> for CsvError {
+    #[inline]
+    fn from(err: ::csv::Error) -> Self {
+        Self::Csv(err)
+    }
+}
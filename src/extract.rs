@@ -0,0 +1,179 @@
+use crate::address::EmailAddress;
+use crate::constants::{MAX_DOMAIN_LENGTH, MAX_IP_LITERAL_LENGTH, MAX_USER_LENGTH};
+#[cfg(feature = "alloc")]
+use crate::deobfuscate::DeobfuscatingExtractor;
+use crate::policy::ValidationPolicy;
+use crate::simd;
+use crate::validator::EmailValidator;
+use core::ops::Range;
+
+/// Local-part characters that are valid `atext` but almost always act as
+/// separators in running text, URLs and `mailto:` queries.
+#[inline(always)]
+const fn is_local_char(byte: u8) -> bool {
+    EmailValidator::is_user_char(byte)
+        && !matches!(byte, b'/' | b'=' | b'?' | b'&' | b'{' | b'|' | b'}')
+}
+
+/// Leading characters dropped from a local part, e.g. the opening quote in
+/// `'user@example.com'`.
+#[inline(always)]
+const fn is_leading_punctuation(byte: u8) -> bool {
+    matches!(byte, b'.' | b'\'' | b'`' | b'*')
+}
+
+/// Byte length of the UTF-8 character at the start of `bytes` when it is
+/// alphanumeric and so may be part of an internationalized domain label.
+fn non_ascii_label_char_len(bytes: &[u8]) -> Option<usize> {
+    let width = match bytes[0] {
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => return None,
+    };
+
+    let ch = core::str::from_utf8(bytes.get(..width)?)
+        .ok()?
+        .chars()
+        .next()?;
+    ch.is_alphanumeric().then_some(width)
+}
+
+/// Iterator over every valid address embedded in arbitrary text, yielding the
+/// byte span of each match together with the parsed address.
+///
+/// Addresses are recognized around their `@`: the local part extends left and
+/// the domain right for as long as the bytes are legal, then surrounding
+/// punctuation such as quotes, `<...>`, a `mailto:` scheme or a sentence's
+/// final `.` is left out of the span. Domains must contain a dot or be an IP
+/// literal, so `@handle@host` style tokens are not reported.
+#[derive(Debug, Clone)]
+pub struct EmailExtractor<'a> {
+    input: &'a [u8],
+    pos: usize,
+    floor: usize,
+    policy: ValidationPolicy,
+}
+
+impl<'a> EmailExtractor<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(text: &'a str) -> Self {
+        Self::from_bytes(text.as_bytes())
+    }
+
+    /// Scans raw bytes, e.g. log files that are not guaranteed to be UTF-8.
+    #[inline]
+    #[must_use]
+    pub const fn from_bytes(input: &'a [u8]) -> Self {
+        Self::with_policy(input, ValidationPolicy::DEFAULT)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_policy(input: &'a [u8], policy: ValidationPolicy) -> Self {
+        Self {
+            input,
+            pos: 0,
+            floor: 0,
+            policy,
+        }
+    }
+
+    /// Opts into recovering obfuscated addresses such as `john [at] example
+    /// [dot] com` in addition to verbatim ones.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn deobfuscate(self) -> DeobfuscatingExtractor<'a> {
+        DeobfuscatingExtractor::new(self.input, self.policy)
+    }
+
+    fn scan_local_part(&self, at: usize) -> usize {
+        let mut start = at;
+        while start > self.floor && is_local_char(self.input[start - 1]) {
+            start -= 1;
+        }
+
+        while start < at && is_leading_punctuation(self.input[start]) {
+            start += 1;
+        }
+
+        start
+    }
+
+    fn scan_domain(&self, from: usize) -> usize {
+        let rest = &self.input[from..];
+
+        if rest.first() == Some(&b'[') {
+            let limit = rest.len().min(MAX_IP_LITERAL_LENGTH);
+            return rest[..limit]
+                .iter()
+                .position(|&b| b == b']')
+                .map_or(from, |close| from + close + 1);
+        }
+
+        let limit = rest.len().min(MAX_DOMAIN_LENGTH + 1);
+        let mut end = 0;
+        while end < limit {
+            let byte = rest[end];
+            if EmailValidator::is_domain_char(byte) || byte == b'.' {
+                end += 1;
+            } else if let Some(width) = (byte > 127)
+                .then(|| non_ascii_label_char_len(&rest[end..]))
+                .flatten()
+            {
+                end += width;
+            } else {
+                break;
+            }
+        }
+
+        while end > 0 && matches!(rest[end - 1], b'.' | b'-') {
+            end -= 1;
+        }
+
+        from + end
+    }
+}
+
+impl<'a> Iterator for EmailExtractor<'a> {
+    type Item = (Range<usize>, EmailAddress<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let at = self.pos + simd::find_byte(&self.input[self.pos..], b'@')?;
+            self.pos = at + 1;
+
+            let start = self.scan_local_part(at);
+            if start == at || at - start > MAX_USER_LENGTH {
+                continue;
+            }
+
+            let end = self.scan_domain(at + 1);
+            if end == at + 1 {
+                continue;
+            }
+
+            let Ok(address) =
+                EmailValidator::parse_with_policy(&self.input[start..end], self.policy)
+            else {
+                continue;
+            };
+
+            if address.is_ip_literal() || address.domain().contains('.') {
+                self.pos = end;
+                self.floor = end;
+                return Some((start..end, address));
+            }
+        }
+    }
+}
+
+impl core::iter::FusedIterator for EmailExtractor<'_> {}
+
+#[inline]
+#[must_use]
+pub const fn extract_emails(text: &str) -> EmailExtractor<'_> {
+    EmailExtractor::new(text)
+}
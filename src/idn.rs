@@ -0,0 +1,473 @@
+//! Conversion of internationalized domains to ASCII.
+//!
+//! Domains in common scripts are converted on the stack, without allocating.
+//! That fast path is a conservative subset of UTS #46: it only accepts the
+//! characters of scripts whose mapping is plain lowercasing, plus fullwidth
+//! ASCII and the ideographic full stops. Labels must also pass the NFC quick
+//! check (a `Maybe` counts as a failure) and, in domains with right-to-left
+//! labels, the RFC 5893 bidi rule. Such labels are then Punycode encoded
+//! (RFC 3492) straight into a [`MAX_DOMAIN_LENGTH`] byte buffer.
+//!
+//! Everything else goes through the full UTS #46 processing of the `idna`
+//! crate with the `alloc` feature, and is rejected without it.
+
+use crate::constants::MAX_DOMAIN_LENGTH;
+use crate::error::EmailValidationError;
+use core::char::ToLowercase;
+use core::fmt;
+
+const ACE_PREFIX: &str = "xn--";
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DIGITS: &[u8; BASE as usize] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// The ASCII form of a domain, held inline.
+#[derive(Clone, Copy)]
+pub struct AsciiDomain {
+    buf: [u8; MAX_DOMAIN_LENGTH],
+    len: usize,
+}
+
+impl AsciiDomain {
+    const fn new() -> Self {
+        Self {
+            buf: [0; MAX_DOMAIN_LENGTH],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // Only ASCII is ever pushed.
+        core::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+
+    fn push(&mut self, byte: u8) -> Result<(), EmailValidationError> {
+        let slot = self
+            .buf
+            .get_mut(self.len)
+            .ok_or(EmailValidationError::IdnProcessingFailed)?;
+        *slot = byte;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn push_str(&mut self, s: &str) -> Result<(), EmailValidationError> {
+        s.bytes().try_for_each(|byte| self.push(byte))
+    }
+
+    fn push_char(&mut self, c: char) -> Result<(), EmailValidationError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl PartialEq for AsciiDomain {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for AsciiDomain {}
+
+impl fmt::Debug for AsciiDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AsciiDomain").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for AsciiDomain {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Converts `domain` to its lowercase ASCII form.
+///
+/// Only domains outside the stack subset allocate. Fails with
+/// `IdnProcessingFailed` when UTS #46 processing fails, or when the result
+/// does not fit in [`MAX_DOMAIN_LENGTH`] bytes. DNS label rules are left to
+/// the domain validator.
+pub fn to_ascii(domain: &str) -> Result<AsciiDomain, EmailValidationError> {
+    to_ascii_on_stack(domain).or_else(|_| to_ascii_with_idna(domain))
+}
+
+fn to_ascii_on_stack(domain: &str) -> Result<AsciiDomain, EmailValidationError> {
+    let bidi_domain = mapped(domain)
+        .any(|c| matches!(bidi_class(c), BidiClass::R | BidiClass::AL | BidiClass::AN));
+
+    let mut out = AsciiDomain::new();
+    for (index, label) in domain.split(is_label_separator).enumerate() {
+        if index > 0 {
+            out.push(b'.')?;
+        }
+        if bidi_domain && !satisfies_bidi_rule(label) {
+            return Err(EmailValidationError::IdnProcessingFailed);
+        }
+        encode_label(label, &mut out)?;
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "alloc")]
+#[cold]
+#[inline(never)]
+fn to_ascii_with_idna(domain: &str) -> Result<AsciiDomain, EmailValidationError> {
+    let ascii =
+        idna::domain_to_ascii(domain).map_err(|_| EmailValidationError::IdnProcessingFailed)?;
+    let mut out = AsciiDomain::new();
+    out.push_str(&ascii)?;
+    Ok(out)
+}
+
+#[cfg(not(feature = "alloc"))]
+#[cold]
+#[inline(never)]
+const fn to_ascii_with_idna(_domain: &str) -> Result<AsciiDomain, EmailValidationError> {
+    Err(EmailValidationError::IdnProcessingFailed)
+}
+
+const fn is_label_separator(c: char) -> bool {
+    matches!(c, '.' | '\u{3002}' | '\u{FF0E}' | '\u{FF61}')
+}
+
+const fn fold_width(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => match char::from_u32(c as u32 - 0xFEE0) {
+            Some(ascii) => ascii,
+            None => c,
+        },
+        _ => c,
+    }
+}
+
+fn map_char(c: char) -> ToLowercase {
+    fold_width(c).to_lowercase()
+}
+
+fn mapped(label: &str) -> impl Iterator<Item = char> + '_ {
+    label.chars().flat_map(map_char)
+}
+
+/// Characters this module can vouch for, before and after mapping: their
+/// UTS #46 mapping is `char::to_lowercase`, they are NFC on their own, and
+/// their bidi class is known to [`bidi_class`].
+const fn is_supported(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_alphanumeric() || c == '-';
+    }
+    matches!(
+        c,
+        // Latin-1 letters, Latin Extended-A and -B, with the letters UTS #46
+        // decomposes or maps elsewhere left out.
+        '\u{00C0}'..='\u{00D6}'
+            | '\u{00D8}'..='\u{00F6}'
+            | '\u{00F8}'..='\u{012F}'
+            | '\u{0131}'
+            | '\u{0134}'..='\u{013E}'
+            | '\u{0141}'..='\u{0148}'
+            | '\u{014A}'..='\u{017E}'
+            | '\u{0180}'..='\u{01C3}'
+            | '\u{01CD}'..='\u{01F0}'
+            | '\u{01F4}'..='\u{024F}'
+            // Combining diacritical marks, less those mapped or ignored.
+            | '\u{0300}'..='\u{033F}'
+            | '\u{0342}'
+            | '\u{0346}'..='\u{034E}'
+            | '\u{0350}'..='\u{036F}'
+            // Greek and Coptic letters.
+            | '\u{0386}'
+            | '\u{0388}'..='\u{038A}'
+            | '\u{038C}'
+            | '\u{038E}'..='\u{03A1}'
+            | '\u{03A3}'..='\u{03CE}'
+            // Cyrillic, Armenian, Hebrew and Arabic letters and digits.
+            | '\u{0400}'..='\u{0481}'
+            | '\u{048A}'..='\u{04BF}'
+            | '\u{04C1}'..='\u{04FF}'
+            | '\u{0531}'..='\u{0556}'
+            | '\u{0561}'..='\u{0586}'
+            | '\u{05D0}'..='\u{05EA}'
+            | '\u{05EF}'..='\u{05F2}'
+            | '\u{061F}'..='\u{063F}'
+            | '\u{0641}'..='\u{064A}'
+            | '\u{0660}'..='\u{0669}'
+            | '\u{066E}'..='\u{066F}'
+            | '\u{0671}'..='\u{0674}'
+            | '\u{0679}'..='\u{06D3}'
+            | '\u{06D5}'
+            | '\u{06EE}'..='\u{06FC}'
+            | '\u{06FF}'
+            // Thai, less the decomposed sara am.
+            | '\u{0E01}'..='\u{0E32}'
+            | '\u{0E34}'..='\u{0E3A}'
+            | '\u{0E40}'..='\u{0E4E}'
+            | '\u{0E50}'..='\u{0E59}'
+            // Georgian, Latin Extended Additional, Kana, CJK and Hangul.
+            | '\u{10D0}'..='\u{10FA}'
+            | '\u{10FD}'..='\u{10FF}'
+            | '\u{1E00}'..='\u{1E99}'
+            | '\u{1E9C}'..='\u{1E9D}'
+            | '\u{1E9F}'..='\u{1EFF}'
+            | '\u{3041}'..='\u{3096}'
+            | '\u{309D}'..='\u{309E}'
+            | '\u{30A1}'..='\u{30FA}'
+            | '\u{30FC}'..='\u{30FE}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFC}'
+            | '\u{AC00}'..='\u{D7A3}'
+    )
+}
+
+/// The supported combining marks, which cannot start a label.
+const fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}' | '\u{0E31}' | '\u{0E34}'..='\u{0E3A}' | '\u{0E47}'..='\u{0E4E}'
+    )
+}
+
+/// Canonical combining class of the supported characters.
+const fn combining_class(c: char) -> u8 {
+    match c {
+        '\u{0334}'..='\u{0338}' => 1,
+        '\u{0E3A}' => 9,
+        '\u{0E38}'..='\u{0E39}' => 103,
+        '\u{0E48}'..='\u{0E4B}' => 107,
+        '\u{0321}'..='\u{0322}' | '\u{0327}'..='\u{0328}' => 202,
+        '\u{031B}' => 216,
+        '\u{0316}'..='\u{0319}'
+        | '\u{031C}'..='\u{0320}'
+        | '\u{0323}'..='\u{0326}'
+        | '\u{0329}'..='\u{0333}'
+        | '\u{0339}'..='\u{033C}'
+        | '\u{0347}'..='\u{0349}'
+        | '\u{034D}'..='\u{034E}'
+        | '\u{0353}'..='\u{0356}'
+        | '\u{0359}'..='\u{035A}' => 220,
+        '\u{0315}' | '\u{031A}' | '\u{0358}' => 232,
+        '\u{035C}' | '\u{035F}' | '\u{0362}' => 233,
+        '\u{035D}'..='\u{035E}' | '\u{0360}'..='\u{0361}' => 234,
+        '\u{0300}'..='\u{036F}' => 230,
+        _ => 0,
+    }
+}
+
+/// Supported characters whose `NFC_Quick_Check` is `Maybe`: they may compose
+/// with what precedes them.
+const fn may_compose(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{0304}'
+            | '\u{0306}'..='\u{030C}'
+            | '\u{030F}'
+            | '\u{0311}'
+            | '\u{0313}'..='\u{0314}'
+            | '\u{031B}'
+            | '\u{0323}'..='\u{0328}'
+            | '\u{032D}'..='\u{032E}'
+            | '\u{0330}'..='\u{0331}'
+            | '\u{0338}'
+            | '\u{0342}'
+    )
+}
+
+/// The UAX #15 NFC quick check over mapped characters, with `Maybe` taken as
+/// a failure. Characters whose quick check is `No` are never supported.
+fn is_nfc(label: &str) -> bool {
+    let mut last_class = 0;
+    mapped(label).all(|c| {
+        let class = combining_class(c);
+        let ordered = class == 0 || last_class <= class;
+        last_class = class;
+        ordered && !may_compose(c)
+    })
+}
+
+/// Bidi classes of the supported characters; RFC 5893 allows no others.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BidiClass {
+    L,
+    R,
+    AL,
+    EN,
+    AN,
+    ES,
+    NSM,
+}
+
+const fn bidi_class(c: char) -> BidiClass {
+    match c {
+        '0'..='9' | '\u{06F0}'..='\u{06F9}' => BidiClass::EN,
+        '-' => BidiClass::ES,
+        '\u{0660}'..='\u{0669}' => BidiClass::AN,
+        '\u{05D0}'..='\u{05FF}' => BidiClass::R,
+        '\u{0600}'..='\u{06FF}' => BidiClass::AL,
+        _ if is_combining_mark(c) => BidiClass::NSM,
+        _ => BidiClass::L,
+    }
+}
+
+/// RFC 5893 section 2, which every label of a domain with a right-to-left
+/// label must satisfy.
+fn satisfies_bidi_rule(label: &str) -> bool {
+    let mut classes = mapped(label).map(bidi_class);
+    let Some(first) = classes.next() else {
+        return true;
+    };
+    let rtl = match first {
+        BidiClass::L => false,
+        BidiClass::R | BidiClass::AL => true,
+        _ => return false,
+    };
+
+    let (mut last, mut en, mut an) = (first, false, false);
+    for class in classes {
+        match class {
+            BidiClass::L if rtl => return false,
+            BidiClass::R | BidiClass::AL | BidiClass::AN if !rtl => return false,
+            BidiClass::NSM => continue,
+            BidiClass::EN => en = true,
+            BidiClass::AN => an = true,
+            _ => {}
+        }
+        last = class;
+    }
+
+    if rtl {
+        !(en && an)
+            && matches!(
+                last,
+                BidiClass::R | BidiClass::AL | BidiClass::EN | BidiClass::AN
+            )
+    } else {
+        matches!(last, BidiClass::L | BidiClass::EN)
+    }
+}
+
+fn encode_label(label: &str, out: &mut AsciiDomain) -> Result<(), EmailValidationError> {
+    if !label.chars().map(fold_width).all(is_supported) || !is_nfc(label) {
+        return Err(EmailValidationError::IdnProcessingFailed);
+    }
+
+    let mut len = 0u32;
+    let mut basic = 0u32;
+    let mut last = None;
+    for c in mapped(label) {
+        if !is_supported(c) || (len == 0 && is_combining_mark(c)) {
+            return Err(EmailValidationError::IdnProcessingFailed);
+        }
+        len += 1;
+        basic += u32::from(c.is_ascii());
+        last = Some(c);
+    }
+
+    if mapped(label).take(ACE_PREFIX.len()).eq(ACE_PREFIX.chars()) {
+        // Decoding and re-checking an existing ACE label is out of scope.
+        return Err(EmailValidationError::IdnProcessingFailed);
+    }
+    if basic == len {
+        return mapped(label).try_for_each(|c| out.push_char(c));
+    }
+    if mapped(label).next() == Some('-') || last == Some('-') {
+        return Err(EmailValidationError::IdnProcessingFailed);
+    }
+
+    out.push_str(ACE_PREFIX)?;
+    mapped(label)
+        .filter(char::is_ascii)
+        .try_for_each(|c| out.push_char(c))?;
+    if basic > 0 {
+        out.push(b'-')?;
+    }
+    encode_punycode(label, len, basic, out)
+}
+
+/// RFC 3492 section 6.3, reading the code points by re-mapping `label` on
+/// every pass instead of buffering them.
+fn encode_punycode(
+    label: &str,
+    len: u32,
+    basic: u32,
+    out: &mut AsciiDomain,
+) -> Result<(), EmailValidationError> {
+    let overflow = || EmailValidationError::IdnProcessingFailed;
+    let mut n = INITIAL_N;
+    let mut delta = 0u32;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic;
+
+    while handled < len {
+        let m = mapped(label)
+            .map(u32::from)
+            .filter(|&c| c >= n)
+            .min()
+            .ok_or_else(overflow)?;
+        delta = (m - n)
+            .checked_mul(handled + 1)
+            .and_then(|d| d.checked_add(delta))
+            .ok_or_else(overflow)?;
+        n = m;
+
+        for c in mapped(label).map(u32::from) {
+            if c < n {
+                delta = delta.checked_add(1).ok_or_else(overflow)?;
+            }
+            if c == n {
+                encode_delta(delta, bias, out)?;
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta = delta.checked_add(1).ok_or_else(overflow)?;
+        n += 1;
+    }
+    Ok(())
+}
+
+fn encode_delta(delta: u32, bias: u32, out: &mut AsciiDomain) -> Result<(), EmailValidationError> {
+    let mut q = delta;
+    let mut k = BASE;
+    loop {
+        let t = k.saturating_sub(bias).clamp(T_MIN, T_MAX);
+        if q < t {
+            break;
+        }
+        out.push(digit(t + (q - t) % (BASE - t)))?;
+        q = (q - t) / (BASE - t);
+        k += BASE;
+    }
+    out.push(digit(q))
+}
+
+#[inline(always)]
+const fn digit(d: u32) -> u8 {
+    DIGITS[d as usize]
+}
+
+const fn adapt(delta: u32, num_points: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + ((BASE - T_MIN + 1) * delta) / (delta + SKEW)
+}
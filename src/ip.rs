@@ -1,4 +1,7 @@
-use std::net::{Ipv4Addr, Ipv6Addr};
+use crate::constants::{IPV6_TAG, MAX_IP_LENGTH, MAX_IP_LITERAL_LENGTH, MAX_IP_ZONE_ID_LENGTH};
+use crate::error::EmailValidationError;
+use crate::policy::ValidationPolicy;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 pub trait ValidateIp {
     fn validate_ip(&self) -> bool;
@@ -7,22 +10,61 @@ pub trait ValidateIp {
 impl ValidateIp for str {
     #[inline]
     fn validate_ip(&self) -> bool {
-        self.len() <= 45 && (self.parse::<Ipv4Addr>().is_ok() || self.parse::<Ipv6Addr>().is_ok())
+        self.len() <= MAX_IP_LENGTH
+            && (self.parse::<Ipv4Addr>().is_ok() || self.parse::<Ipv6Addr>().is_ok())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpLiteral<'a> {
+    V4(Ipv4Addr),
+    V6 {
+        addr: Ipv6Addr,
+        zone_id: Option<&'a str>,
+    },
+}
+
+impl<'a> IpLiteral<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn addr(&self) -> IpAddr {
+        match *self {
+            Self::V4(addr) => IpAddr::V4(addr),
+            Self::V6 { addr, .. } => IpAddr::V6(addr),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn zone_id(&self) -> Option<&'a str> {
+        match *self {
+            Self::V4(_) => None,
+            Self::V6 { zone_id, .. } => zone_id,
+        }
     }
 }
 
 #[inline(always)]
-pub fn is_valid_ipv4_char(byte: u8) -> bool {
+#[must_use]
+pub const fn is_valid_ipv4_char(byte: u8) -> bool {
     matches!(byte, b'0'..=b'9' | b'.')
 }
 
 #[inline(always)]
-pub fn is_valid_ipv6_char(byte: u8) -> bool {
+#[must_use]
+pub const fn is_valid_ipv6_char(byte: u8) -> bool {
     matches!(byte, b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F' | b':')
 }
 
+#[inline(always)]
+#[must_use]
+pub const fn is_valid_zone_id_char(byte: u8) -> bool {
+    matches!(byte, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~')
+}
+
+#[must_use]
 pub fn fast_ip_precheck(bytes: &[u8]) -> bool {
-    if bytes.is_empty() || bytes.len() > 45 {
+    if bytes.is_empty() || bytes.len() > MAX_IP_LENGTH {
         return false;
     }
 
@@ -39,3 +81,76 @@ pub fn fast_ip_precheck(bytes: &[u8]) -> bool {
 
     false
 }
+
+#[inline]
+fn parse_zone_id(bytes: &[u8]) -> Option<&str> {
+    if bytes.is_empty()
+        || bytes.len() > MAX_IP_ZONE_ID_LENGTH
+        || !bytes.iter().all(|&b| is_valid_zone_id_char(b))
+    {
+        return None;
+    }
+
+    // SAFETY: every byte was checked to be ASCII above.
+    Some(unsafe { core::str::from_utf8_unchecked(bytes) })
+}
+
+/// Parses a bracketed domain literal such as `[127.0.0.1]`, `[::1]` or
+/// `[IPv6:fe80::1%eth0]`.
+///
+/// A `%zone` suffix is only meaningful for IPv6 and is rejected with
+/// [`EmailValidationError::IpZoneIdNotAllowed`] unless the policy allows it.
+pub fn parse_ip_literal(
+    bytes: &[u8],
+    policy: ValidationPolicy,
+) -> Result<IpLiteral<'_>, EmailValidationError> {
+    let len = bytes.len();
+
+    if !(3..=MAX_IP_LITERAL_LENGTH).contains(&len) || bytes[0] != b'[' || bytes[len - 1] != b']' {
+        return Err(EmailValidationError::InvalidIpLiteral);
+    }
+
+    let mut inner = &bytes[1..len - 1];
+    let tagged =
+        inner.len() > IPV6_TAG.len() && inner[..IPV6_TAG.len()].eq_ignore_ascii_case(IPV6_TAG);
+    if tagged {
+        inner = &inner[IPV6_TAG.len()..];
+    }
+
+    let (ip_bytes, zone_bytes) = inner
+        .iter()
+        .position(|&b| b == b'%')
+        .map_or((inner, None), |pos| {
+            (&inner[..pos], Some(&inner[pos + 1..]))
+        });
+
+    if !fast_ip_precheck(ip_bytes) {
+        return Err(EmailValidationError::InvalidIpLiteral);
+    }
+
+    // SAFETY: `fast_ip_precheck` only admits ASCII bytes.
+    let ip_str = unsafe { core::str::from_utf8_unchecked(ip_bytes) };
+
+    if let Ok(addr) = ip_str.parse::<Ipv6Addr>() {
+        let zone_id = match zone_bytes {
+            None => None,
+            Some(zone) => {
+                let zone_id = parse_zone_id(zone).ok_or(EmailValidationError::InvalidIpLiteral)?;
+                if !policy.allows_ip_zone_id() {
+                    return Err(EmailValidationError::IpZoneIdNotAllowed);
+                }
+                Some(zone_id)
+            }
+        };
+        return Ok(IpLiteral::V6 { addr, zone_id });
+    }
+
+    if tagged || zone_bytes.is_some() {
+        return Err(EmailValidationError::InvalidIpLiteral);
+    }
+
+    ip_str
+        .parse::<Ipv4Addr>()
+        .map(IpLiteral::V4)
+        .map_err(|_| EmailValidationError::InvalidIpLiteral)
+}
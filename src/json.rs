@@ -0,0 +1,24 @@
+//! The JSON string escaping shared by the CLI output and the web extractor
+//! rejection bodies.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Appends `value` to `out` as a quoted JSON string.
+pub fn push_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if ch.is_control() => {
+                let _ = write!(out, "\\u{:04x}", u32::from(ch));
+            }
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
@@ -1,25 +1,138 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_op_in_unsafe_fn)]
 #![deny(clippy::all, clippy::pedantic, clippy::nursery)]
-#![allow(clippy::module_name_repetitions, clippy::inline_always)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::inline_always,
+    clippy::missing_errors_doc
+)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "actix-web")]
+pub mod actix;
+pub mod address;
+#[cfg(feature = "alloc")]
+pub mod address_list;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "clap")]
+pub mod clap;
 pub mod constants;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "alloc")]
+pub mod deobfuscate;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "alloc")]
+pub mod email;
+#[cfg(feature = "alloc")]
+pub mod encoded_word;
 pub mod error;
+pub mod extract;
+pub mod idn;
 pub mod ip;
+#[cfg(feature = "alloc")]
+mod json;
+#[cfg(feature = "alloc")]
+pub mod mailbox;
+#[cfg(feature = "alloc")]
+pub mod mailto;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod policy;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod simd;
+pub mod smtp;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "std")]
+pub mod stream;
 pub mod traits;
 pub mod types;
 pub mod validator;
+#[cfg(feature = "validator")]
+pub mod validator_compat;
 
-pub use error::EmailValidationError;
+pub use address::EmailAddress;
+#[cfg(feature = "alloc")]
+pub use address_list::{parse_address_list, AddressList, AddressListEntry};
+#[cfg(feature = "clap")]
+pub use clap::EmailValueParser;
+#[cfg(feature = "csv")]
+pub use csv::{CsvColumn, CsvReport, CsvValidator};
+#[cfg(feature = "alloc")]
+pub use deobfuscate::DeobfuscatingExtractor;
+#[cfg(feature = "alloc")]
+pub use email::Email;
+#[cfg(feature = "derive")]
+pub use email_syntax_verify_opt_derive::ValidateEmails;
+#[cfg(feature = "csv")]
+pub use error::CsvError;
+#[cfg(feature = "alloc")]
+pub use error::{EmailFieldError, EmailFieldErrors};
+pub use error::{EmailValidationError, EncodedWordError, MailboxError, MailtoError, SmtpPathError};
+pub use extract::{extract_emails, EmailExtractor};
+pub use ip::IpLiteral;
+#[cfg(feature = "alloc")]
+pub use mailbox::{format_display_name, Mailbox};
+#[cfg(feature = "alloc")]
+pub use mailto::{MailtoBuilder, MailtoUri};
+#[cfg(feature = "rayon")]
+pub use parallel::{par_parse, par_validate, ParallelValidateEmail};
+pub use policy::ValidationPolicy;
+pub use smtp::{EnvelopeCommand, SmtpCommand, SmtpPath};
+#[cfg(feature = "std")]
+pub use stream::{EmailStream, StreamEntry};
 pub use traits::ValidateEmail;
+#[cfg(feature = "alloc")]
+pub use traits::ValidateEmails;
 pub use types::ValidationResult;
 pub use validator::EmailValidator;
 
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+pub mod __private {
+    //! Items used by `#[derive(ValidateEmails)]` expansions, which cannot
+    //! name `alloc` themselves in `no_std` crates, and by the CLI binary.
+    pub use crate::json::push_json_string;
+    pub use alloc::vec::Vec;
+}
+
 #[inline]
+#[must_use]
 pub fn validate_email(email: &str) -> bool {
     EmailValidator::validate_str(email)
 }
 
 #[inline]
+#[must_use]
 pub fn validate_email_bytes(email: &[u8]) -> bool {
     EmailValidator::validate(email)
 }
+
+/// Validates an address literal at compile time, evaluating to an
+/// `EmailAddress<'static>`.
+///
+/// Invalid literals fail the build with the validation error. The check is
+/// [`EmailValidator::parse_const`], so IP literals and internationalized
+/// domains are not accepted.
+///
+/// ```compile_fail
+/// const SENDER: email_syntax_verify_opt::EmailAddress<'static> =
+///     email_syntax_verify_opt::email!("noreply.ourcorp.com");
+/// ```
+#[macro_export]
+macro_rules! email {
+    ($email:expr $(,)?) => {{
+        const ADDRESS: $crate::EmailAddress<'static> =
+            match $crate::EmailValidator::parse_const($email) {
+                ::core::result::Result::Ok(address) => address,
+                ::core::result::Result::Err(error) => ::core::panic!("{}", error.as_str()),
+            };
+        ADDRESS
+    }};
+}
@@ -0,0 +1,441 @@
+use crate::address::EmailAddress;
+use crate::encoded_word::{decode_word, encode_if_needed, is_encoded_word, needs_encoding};
+use crate::error::MailboxError;
+use crate::policy::ValidationPolicy;
+use crate::validator::EmailValidator;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mailbox<'a> {
+    display_name: Option<Cow<'a, str>>,
+    address: EmailAddress<'a>,
+    has_encoded_words: bool,
+}
+
+impl<'a> Mailbox<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(display_name: Option<Cow<'a, str>>, address: EmailAddress<'a>) -> Self {
+        Self {
+            display_name,
+            address,
+            has_encoded_words: false,
+        }
+    }
+
+    /// Parses a single `name-addr` or `addr-spec`, e.g. `"Doe, John" <john@example.com>`,
+    /// `John Doe <john@example.com>` or `john@example.com`.
+    #[inline]
+    pub fn parse(input: &'a str) -> Result<Self, MailboxError> {
+        Self::parse_with_policy(input, ValidationPolicy::DEFAULT)
+    }
+
+    pub fn parse_with_policy(
+        input: &'a str,
+        policy: ValidationPolicy,
+    ) -> Result<Self, MailboxError> {
+        let mut cursor = Cursor::new(input);
+        cursor.skip_cfws()?;
+        if cursor.is_at_end() {
+            return Err(MailboxError::Empty);
+        }
+
+        let (end, delimiter) = cursor.scan_delimiter()?;
+        if !matches!(delimiter, None | Some(b'<')) {
+            return Err(MailboxError::UnexpectedCharacter);
+        }
+
+        let mailbox = cursor.parse_mailbox(end, delimiter, policy)?;
+        if !cursor.is_at_end() {
+            return Err(MailboxError::UnexpectedCharacter);
+        }
+
+        Ok(mailbox)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn address(&self) -> EmailAddress<'a> {
+        self.address
+    }
+
+    /// Whether the display name was written with RFC 2047 encoded-words such
+    /// as `=?UTF-8?B?...?=`. Those that could be decoded already have been.
+    #[inline]
+    #[must_use]
+    pub const fn has_encoded_words(&self) -> bool {
+        self.has_encoded_words
+    }
+}
+
+impl fmt::Display for Mailbox<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.display_name() {
+            Some(name) => write!(f, "{} <{}>", format_display_name(name), self.address),
+            None => f.write_str(self.address.as_str()),
+        }
+    }
+}
+
+/// Renders `name` as a header-safe RFC 5322 phrase: plain atoms are kept,
+/// anything with specials is quoted and escaped, and non-ASCII or control
+/// characters are RFC 2047 encoded.
+#[must_use]
+pub fn format_display_name(name: &str) -> Cow<'_, str> {
+    if needs_encoding(name) {
+        return encode_if_needed(name);
+    }
+
+    let is_plain = !name.is_empty()
+        && name
+            .split(' ')
+            .all(|word| !word.is_empty() && word.bytes().all(is_atext) && !is_encoded_word(word));
+    if is_plain {
+        return Cow::Borrowed(name);
+    }
+
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('"');
+    for c in name.chars() {
+        if matches!(c, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+
+    Cow::Owned(quoted)
+}
+
+#[inline(always)]
+pub(crate) const fn is_atext(byte: u8) -> bool {
+    matches!(byte,
+        b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' |
+        b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'/' |
+        b'=' | b'?' | b'^' | b'_' | b'`' | b'{' | b'|' | b'}' | b'~' |
+        0x80..=0xFF
+    )
+}
+
+#[inline(always)]
+const fn is_wsp(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\r' | b'\n')
+}
+
+#[inline(always)]
+const fn is_phrase_char(byte: u8) -> bool {
+    is_atext(byte) || byte == b'.'
+}
+
+pub(crate) struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    #[inline]
+    pub(crate) const fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    #[inline(always)]
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    #[inline(always)]
+    pub(crate) const fn is_at_end(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    #[inline(always)]
+    pub(crate) const fn bump(&mut self) {
+        self.pos += 1;
+    }
+
+    #[inline(always)]
+    pub(crate) const fn advance_to(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    #[inline(always)]
+    pub(crate) fn slice_to(&self, end: usize) -> &'a str {
+        &self.input[self.pos..end]
+    }
+
+    pub(crate) fn skip_cfws(&mut self) -> Result<(), MailboxError> {
+        while let Some(byte) = self.peek() {
+            if is_wsp(byte) {
+                self.pos += 1;
+            } else if byte == b'(' {
+                self.pos = skip_comment(self.input.as_bytes(), self.pos)?;
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds the first of `<`, `:`, `,` or `;` outside quoted strings,
+    /// comments and domain literals, starting at the cursor.
+    pub(crate) fn scan_delimiter(&self) -> Result<(usize, Option<u8>), MailboxError> {
+        let bytes = self.input.as_bytes();
+        let mut pos = self.pos;
+
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'"' => pos = skip_quoted_string(bytes, pos)?,
+                b'(' => pos = skip_comment(bytes, pos)?,
+                b'[' => {
+                    pos = bytes[pos..]
+                        .iter()
+                        .position(|&b| b == b']')
+                        .map_or(bytes.len(), |offset| pos + offset + 1);
+                }
+                delimiter @ (b'<' | b':' | b',' | b';') => return Ok((pos, Some(delimiter))),
+                _ => pos += 1,
+            }
+        }
+
+        Ok((bytes.len(), None))
+    }
+
+    /// Parses a single mailbox that starts at the cursor and ends at `end`,
+    /// where `delimiter` is the byte found there by [`Self::scan_delimiter`].
+    pub(crate) fn parse_mailbox(
+        &mut self,
+        end: usize,
+        delimiter: Option<u8>,
+        policy: ValidationPolicy,
+    ) -> Result<Mailbox<'a>, MailboxError> {
+        if delimiter == Some(b'<') {
+            let phrase = decode_phrase(&self.input[self.pos..end])?;
+            self.pos = end + 1;
+            return self.parse_angle_addr(phrase, policy);
+        }
+
+        let start = self.pos;
+        let bytes = self.input.as_bytes();
+        while self.pos < end && !is_wsp(bytes[self.pos]) && bytes[self.pos] != b'(' {
+            self.pos += 1;
+        }
+
+        if self.pos == start {
+            return Err(MailboxError::Empty);
+        }
+
+        let address = EmailValidator::parse_with_policy(&bytes[start..self.pos], policy)?;
+        self.skip_cfws()?;
+        if self.pos != end {
+            return Err(MailboxError::UnexpectedCharacter);
+        }
+
+        Ok(Mailbox::new(None, address))
+    }
+
+    fn parse_angle_addr(
+        &mut self,
+        phrase: Option<Phrase<'a>>,
+        policy: ValidationPolicy,
+    ) -> Result<Mailbox<'a>, MailboxError> {
+        let bytes = self.input.as_bytes();
+        let close = bytes[self.pos..]
+            .iter()
+            .position(|&b| b == b'>')
+            .map(|offset| self.pos + offset)
+            .ok_or(MailboxError::UnterminatedAngleAddr)?;
+
+        let addr_spec = self.input[self.pos..close].trim_matches([' ', '\t', '\r', '\n']);
+        let address = EmailValidator::parse_with_policy(addr_spec.as_bytes(), policy)?;
+
+        self.pos = close + 1;
+        self.skip_cfws()?;
+
+        Ok(match phrase {
+            Some(phrase) => Mailbox {
+                display_name: Some(phrase.text),
+                address,
+                has_encoded_words: phrase.has_encoded_words,
+            },
+            None => Mailbox::new(None, address),
+        })
+    }
+}
+
+fn skip_comment(bytes: &[u8], start: usize) -> Result<usize, MailboxError> {
+    let mut depth = 0usize;
+    let mut pos = start;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 1,
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(pos + 1);
+                }
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
+
+    Err(MailboxError::UnterminatedComment)
+}
+
+fn skip_quoted_string(bytes: &[u8], start: usize) -> Result<usize, MailboxError> {
+    let mut pos = start + 1;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            b'"' => return Ok(pos + 1),
+            _ => pos += 1,
+        }
+    }
+
+    Err(MailboxError::UnterminatedQuotedString)
+}
+
+fn unquote_into(content: &str, out: &mut String) {
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.extend(chars.next()),
+            '\r' | '\n' => {}
+            _ => out.push(c),
+        }
+    }
+}
+
+pub(crate) struct Phrase<'a> {
+    pub(crate) text: Cow<'a, str>,
+    pub(crate) has_encoded_words: bool,
+}
+
+/// Decodes an RFC 5322 phrase, dropping comments, removing quoting and
+/// folding runs of whitespace between words into a single space.
+///
+/// The result borrows from `phrase` whenever no rewriting is required.
+pub(crate) fn decode_phrase(phrase: &str) -> Result<Option<Phrase<'_>>, MailboxError> {
+    let bytes = phrase.as_bytes();
+    let mut cursor = Cursor::new(phrase);
+    let mut words = 0usize;
+    let mut first = 0;
+    let mut last = 0;
+    let mut simple = true;
+    let mut has_encoded_words = false;
+
+    loop {
+        let before = cursor.pos;
+        cursor.skip_cfws()?;
+        if cursor.is_at_end() {
+            break;
+        }
+
+        if words > 0 && (cursor.pos - before != 1 || bytes[before] != b' ') {
+            simple = false;
+        }
+
+        let start = cursor.pos;
+        match bytes[start] {
+            b'"' => {
+                cursor.pos = skip_quoted_string(bytes, start)?;
+                simple = false;
+            }
+            byte if is_phrase_char(byte) => {
+                while cursor.peek().is_some_and(is_phrase_char) {
+                    cursor.bump();
+                }
+                has_encoded_words |= is_encoded_word(&phrase[start..cursor.pos]);
+            }
+            _ => return Err(MailboxError::InvalidDisplayName),
+        }
+
+        if words == 0 {
+            first = start;
+        }
+        last = cursor.pos;
+        words += 1;
+    }
+
+    if words == 0 {
+        return Ok(None);
+    }
+
+    let trimmed = &phrase[first..last];
+    let text = if simple && !has_encoded_words {
+        Cow::Borrowed(trimmed)
+    } else {
+        unquote_phrase(trimmed, words)?
+    };
+
+    Ok(Some(Phrase {
+        text,
+        has_encoded_words,
+    }))
+}
+
+fn unquote_phrase(trimmed: &str, words: usize) -> Result<Cow<'_, str>, MailboxError> {
+    if words == 1 && trimmed.starts_with('"') {
+        let inner = &trimmed[1..trimmed.len() - 1];
+        if !inner.contains(['\\', '\r', '\n']) {
+            return Ok(Cow::Borrowed(inner));
+        }
+    }
+
+    let bytes = trimmed.as_bytes();
+    let mut decoded = String::with_capacity(trimmed.len());
+    let mut cursor = Cursor::new(trimmed);
+    let mut first_word = true;
+    let mut prev_encoded = false;
+
+    loop {
+        cursor.skip_cfws()?;
+        if cursor.is_at_end() {
+            break;
+        }
+
+        let start = cursor.pos;
+        if bytes[start] == b'"' {
+            cursor.pos = skip_quoted_string(bytes, start)?;
+            if !first_word {
+                decoded.push(' ');
+            }
+            unquote_into(&trimmed[start + 1..cursor.pos - 1], &mut decoded);
+            prev_encoded = false;
+        } else {
+            while cursor.peek().is_some_and(is_phrase_char) {
+                cursor.bump();
+            }
+
+            let word = &trimmed[start..cursor.pos];
+            // Whitespace between adjacent encoded-words is not part of the text (RFC 2047 6.2).
+            if let Ok(text) = decode_word(word) {
+                if !first_word && !prev_encoded {
+                    decoded.push(' ');
+                }
+                decoded.push_str(&text);
+                prev_encoded = true;
+            } else {
+                if !first_word {
+                    decoded.push(' ');
+                }
+                decoded.push_str(word);
+                prev_encoded = false;
+            }
+        }
+        first_word = false;
+    }
+
+    Ok(Cow::Owned(decoded))
+}
@@ -0,0 +1,296 @@
+use crate::address::EmailAddress;
+use crate::error::MailtoError;
+use crate::policy::ValidationPolicy;
+use crate::validator::EmailValidator;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+const SCHEME: &str = "mailto:";
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// RFC 6068 `qchar`: unreserved characters plus the `some-delims` set.
+#[inline(always)]
+const fn is_qchar(byte: u8) -> bool {
+    matches!(byte,
+        b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' |
+        b'!' | b'$' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b':' | b'@'
+    )
+}
+
+const fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn percent_decode(input: &str) -> Result<String, MailtoError> {
+    if !input.contains('%') {
+        return Ok(input.to_owned());
+    }
+
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        if bytes[pos] == b'%' {
+            let high = bytes.get(pos + 1).copied().and_then(hex_value);
+            let low = bytes.get(pos + 2).copied().and_then(hex_value);
+            let (Some(high), Some(low)) = (high, low) else {
+                return Err(MailtoError::InvalidPercentEncoding);
+            };
+            out.push((high << 4) | low);
+            pos += 3;
+        } else {
+            out.push(bytes[pos]);
+            pos += 1;
+        }
+    }
+
+    String::from_utf8(out).map_err(|_| MailtoError::InvalidUtf8)
+}
+
+fn percent_encode(input: &str, out: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut start = 0;
+
+    for (pos, &byte) in input.as_bytes().iter().enumerate() {
+        if !is_qchar(byte) {
+            // `start < pos` only after a run of ASCII qchars, so the slice
+            // never splits a multi-byte character.
+            if start < pos {
+                out.write_str(&input[start..pos])?;
+            }
+            write!(
+                out,
+                "%{}{}",
+                char::from(HEX_DIGITS[usize::from(byte >> 4)]),
+                char::from(HEX_DIGITS[usize::from(byte & 0x0F)])
+            )?;
+            start = pos + 1;
+        }
+    }
+
+    out.write_str(&input[start..])
+}
+
+fn push_addresses(
+    list: &mut Vec<String>,
+    value: &str,
+    policy: ValidationPolicy,
+) -> Result<(), MailtoError> {
+    for part in value.split(',') {
+        let decoded = percent_decode(part)?;
+        let address = decoded.trim();
+        if address.is_empty() {
+            continue;
+        }
+
+        EmailValidator::parse_with_policy(address.as_bytes(), policy)?;
+        list.push(address.to_owned());
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MailtoUri {
+    to: Vec<String>,
+    cc: Vec<String>,
+    bcc: Vec<String>,
+    subject: Option<String>,
+    body: Option<String>,
+    headers: Vec<(String, String)>,
+}
+
+impl MailtoUri {
+    /// Parses an RFC 6068 `mailto:` URI, percent-decoding every component and
+    /// validating all `to`, `cc` and `bcc` recipients.
+    #[inline]
+    pub fn parse(uri: &str) -> Result<Self, MailtoError> {
+        Self::parse_with_policy(uri, ValidationPolicy::DEFAULT)
+    }
+
+    pub fn parse_with_policy(uri: &str, policy: ValidationPolicy) -> Result<Self, MailtoError> {
+        let rest = uri
+            .get(..SCHEME.len())
+            .filter(|scheme| scheme.eq_ignore_ascii_case(SCHEME))
+            .map(|_| &uri[SCHEME.len()..])
+            .ok_or(MailtoError::MissingScheme)?;
+
+        let (to, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let mut parsed = Self::default();
+        push_addresses(&mut parsed.to, to, policy)?;
+
+        for field in query.split('&').filter(|field| !field.is_empty()) {
+            let (name, value) = field.split_once('=').unwrap_or((field, ""));
+            let name = percent_decode(name)?;
+            if name.is_empty() {
+                return Err(MailtoError::InvalidHeader);
+            }
+
+            match name.to_ascii_lowercase().as_str() {
+                "to" => push_addresses(&mut parsed.to, value, policy)?,
+                "cc" => push_addresses(&mut parsed.cc, value, policy)?,
+                "bcc" => push_addresses(&mut parsed.bcc, value, policy)?,
+                "subject" => parsed.subject = Some(percent_decode(value)?),
+                "body" => parsed.body = Some(percent_decode(value)?),
+                _ => parsed.headers.push((name, percent_decode(value)?)),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to(&self) -> &[String] {
+        &self.to
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cc(&self) -> &[String] {
+        &self.cc
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn bcc(&self) -> &[String] {
+        &self.bcc
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Header fields other than `to`, `cc`, `bcc`, `subject` and `body`.
+    #[inline]
+    #[must_use]
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+}
+
+impl fmt::Display for MailtoUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_list(f: &mut fmt::Formatter<'_>, list: &[String]) -> fmt::Result {
+            for (i, address) in list.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                percent_encode(address, f)?;
+            }
+            Ok(())
+        }
+
+        f.write_str(SCHEME)?;
+        write_list(f, &self.to)?;
+
+        let mut separator = '?';
+        for (name, list) in [("cc", &self.cc), ("bcc", &self.bcc)] {
+            if !list.is_empty() {
+                write!(f, "{separator}{name}=")?;
+                write_list(f, list)?;
+                separator = '&';
+            }
+        }
+
+        let fields = [
+            ("subject", self.subject.as_deref()),
+            ("body", self.body.as_deref()),
+        ];
+        let fields = fields
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .chain(
+                self.headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str())),
+            );
+
+        for (name, value) in fields {
+            write!(f, "{separator}")?;
+            percent_encode(name, f)?;
+            f.write_str("=")?;
+            percent_encode(value, f)?;
+            separator = '&';
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MailtoBuilder {
+    uri: MailtoUri,
+}
+
+impl MailtoBuilder {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to(mut self, address: EmailAddress<'_>) -> Self {
+        self.uri.to.push(address.as_str().to_owned());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cc(mut self, address: EmailAddress<'_>) -> Self {
+        self.uri.cc.push(address.as_str().to_owned());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn bcc(mut self, address: EmailAddress<'_>) -> Self {
+        self.uri.bcc.push(address.as_str().to_owned());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.uri.subject = Some(subject.into());
+        self
+    }
+
+    /// Sets the body; bare line feeds are normalized to CRLF as RFC 6068 requires.
+    #[must_use]
+    pub fn body(mut self, body: &str) -> Self {
+        self.uri.body = Some(body.replace("\r\n", "\n").replace('\n', "\r\n"));
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.uri.headers.push((name.into(), value.into()));
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn build(self) -> MailtoUri {
+        self.uri
+    }
+}
@@ -1,14 +1,417 @@
-use email_syntax_verify_opt::ValidateEmail;
-
-fn main() {
-    let test_emails = [
-        "test@example.com",
-        "invalid.email",
-        "user@domain.co.uk",
-        "email@[127.0.0.1]",
-    ];
-
-    for email in test_emails {
-        println!("{}: {}", email, email.validate_email());
+use email_syntax_verify_opt::__private::push_json_string;
+use email_syntax_verify_opt::{
+    EmailAddress, EmailStream, EmailValidationError, EmailValidator, ValidationPolicy,
+};
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: email_syntax_verify_opt [OPTIONS] [ADDRESS]...
+       email_syntax_verify_opt clean [CLEAN OPTIONS] INPUT
+
+Validates each ADDRESS, or newline-delimited addresses from standard input
+when none are given, printing one tab-separated `valid` or `invalid` line per
+address.
+
+Options:
+  -r, --reason            Append the rejection reason to invalid lines
+  -j, --json              Print one JSON object per line instead
+  -c, --canonicalize      Print valid addresses in canonical form
+  -q, --quiet             Print nothing; only set the exit status
+      --allow-ip-zone-id  Accept zone identifiers in IPv6 literals
+  -h, --help              Print this help and exit
+  -V, --version           Print the version and exit
+
+Exit status: 0 if every address is valid, 1 if any is invalid, 2 on usage or
+I/O errors.
+
+The clean subcommand splits the newline-delimited list in INPUT (`-` for
+standard input) into three files and prints a summary. Valid addresses are
+written once each in canonical form, rejected lines with their line number and
+reason, and lines repeating an earlier address with both line numbers. Blank
+lines are skipped.
+
+Clean options:
+      --valid FILE        Valid output [default: INPUT_STEM.valid.txt]
+      --invalid FILE      Invalid output [default: INPUT_STEM.invalid.txt]
+      --duplicates FILE   Duplicates output [default: INPUT_STEM.duplicates.txt]
+      --top N             Invalid domains listed in the summary [default: 10]
+      --allow-ip-zone-id  Accept zone identifiers in IPv6 literals";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Quiet,
+}
+
+#[derive(Debug)]
+struct Options {
+    format: Format,
+    reason: bool,
+    canonicalize: bool,
+    policy: ValidationPolicy,
+    addresses: Vec<String>,
+}
+
+#[derive(Debug)]
+struct CleanOptions {
+    input: PathBuf,
+    valid: Option<PathBuf>,
+    invalid: Option<PathBuf>,
+    duplicates: Option<PathBuf>,
+    top: usize,
+    policy: ValidationPolicy,
+}
+
+enum Command {
+    Run(Options),
+    Clean(CleanOptions),
+    Help,
+    Version,
+}
+
+fn option_value(args: &mut impl Iterator<Item = String>, name: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("option `{name}` requires a value"))
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.peekable();
+    if args.next_if(|arg| arg == "clean").is_some() {
+        return parse_clean_args(args);
+    }
+
+    let mut options = Options {
+        format: Format::Text,
+        reason: false,
+        canonicalize: false,
+        policy: ValidationPolicy::DEFAULT,
+        addresses: Vec::new(),
+    };
+    let mut only_addresses = false;
+
+    for arg in args {
+        if only_addresses || !arg.starts_with('-') {
+            options.addresses.push(arg);
+            continue;
+        }
+
+        match arg.as_str() {
+            "--" => only_addresses = true,
+            "-r" | "--reason" => options.reason = true,
+            "-j" | "--json" => options.format = Format::Json,
+            "-c" | "--canonicalize" => options.canonicalize = true,
+            "-q" | "--quiet" => options.format = Format::Quiet,
+            "--allow-ip-zone-id" => options.policy = options.policy.allow_ip_zone_id(true),
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            _ => return Err(format!("unknown option `{arg}`")),
+        }
+    }
+
+    Ok(Command::Run(options))
+}
+
+fn parse_clean_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut input = None;
+    let mut options = CleanOptions {
+        input: PathBuf::new(),
+        valid: None,
+        invalid: None,
+        duplicates: None,
+        top: 10,
+        policy: ValidationPolicy::DEFAULT,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--valid" => options.valid = Some(option_value(&mut args, &arg)?.into()),
+            "--invalid" => options.invalid = Some(option_value(&mut args, &arg)?.into()),
+            "--duplicates" => options.duplicates = Some(option_value(&mut args, &arg)?.into()),
+            "--top" => {
+                let value = option_value(&mut args, &arg)?;
+                options.top = value
+                    .parse()
+                    .map_err(|_| format!("invalid value `{value}` for `--top`"))?;
+            }
+            "--allow-ip-zone-id" => options.policy = options.policy.allow_ip_zone_id(true),
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option `{arg}`"))
+            }
+            _ if input.is_some() => return Err(format!("unexpected argument `{arg}`")),
+            _ => input = Some(PathBuf::from(arg)),
+        }
+    }
+
+    options.input = input.ok_or("clean requires an INPUT file")?;
+    Ok(Command::Clean(options))
+}
+
+struct Report<W> {
+    output: W,
+    options: Options,
+    line: String,
+    all_valid: bool,
+}
+
+impl<W: Write> Report<W> {
+    fn entry(
+        &mut self,
+        input: &[u8],
+        result: Result<EmailAddress<'_>, EmailValidationError>,
+    ) -> io::Result<()> {
+        self.all_valid &= result.is_ok();
+        let input = String::from_utf8_lossy(input);
+        let canonical = match result {
+            Ok(address) if self.options.canonicalize => Some(address.canonical()),
+            _ => None,
+        };
+
+        self.line.clear();
+        match self.options.format {
+            Format::Quiet => return Ok(()),
+            Format::Text => {
+                let shown = canonical.as_deref().unwrap_or(&input);
+                match result {
+                    Ok(_) => {
+                        let _ = write!(self.line, "valid\t{shown}");
+                    }
+                    Err(err) => {
+                        let _ = write!(self.line, "invalid\t{shown}");
+                        if self.options.reason {
+                            let _ = write!(self.line, "\t{}", err.code());
+                        }
+                    }
+                }
+            }
+            Format::Json => {
+                self.line.push_str("{\"input\":");
+                push_json_string(&mut self.line, &input);
+                let _ = write!(self.line, ",\"valid\":{}", result.is_ok());
+                if let Some(canonical) = &canonical {
+                    self.line.push_str(",\"canonical\":");
+                    push_json_string(&mut self.line, canonical);
+                }
+                if let (Err(err), true) = (result, self.options.reason) {
+                    let _ = write!(self.line, ",\"reason\":\"{}\",\"message\":", err.code());
+                    push_json_string(&mut self.line, err.as_str());
+                }
+                self.line.push('}');
+            }
+        }
+
+        self.line.push('\n');
+        self.output.write_all(self.line.as_bytes())
+    }
+}
+
+fn run(mut options: Options) -> io::Result<bool> {
+    let policy = options.policy;
+    let addresses = std::mem::take(&mut options.addresses);
+    let mut report = Report {
+        output: BufWriter::new(io::stdout().lock()),
+        options,
+        line: String::new(),
+        all_valid: true,
+    };
+
+    if addresses.is_empty() {
+        let mut stream = EmailStream::with_policy(io::stdin().lock(), policy);
+        while let Some(entry) = stream.next_entry() {
+            let (_, input, result) = entry?;
+            report.entry(input, result)?;
+        }
+    } else {
+        for address in &addresses {
+            let bytes = address.as_bytes();
+            report.entry(bytes, EmailValidator::parse_with_policy(bytes, policy))?;
+        }
+    }
+
+    report.output.flush()?;
+    Ok(report.all_valid)
+}
+
+/// Tallies gathered while cleaning a list, printed as the summary.
+#[derive(Debug, Default)]
+struct CleanSummary {
+    lines: usize,
+    valid: usize,
+    invalid: usize,
+    duplicates: usize,
+    idn: usize,
+    ip_literals: usize,
+    reasons: HashMap<&'static str, usize>,
+    invalid_domains: HashMap<String, usize>,
+}
+
+impl CleanSummary {
+    fn write_to(&self, out: &mut impl Write, top: usize) -> io::Result<()> {
+        writeln!(out, "Lines read:        {}", self.lines)?;
+        writeln!(out, "Valid addresses:   {}", self.valid)?;
+        writeln!(out, "  IDN domains:     {}", self.idn)?;
+        writeln!(out, "  IP literals:     {}", self.ip_literals)?;
+        writeln!(out, "Duplicates:        {}", self.duplicates)?;
+        writeln!(out, "Invalid addresses: {}", self.invalid)?;
+
+        if !self.reasons.is_empty() {
+            writeln!(out, "\nInvalid by reason:")?;
+            for (code, count) in sorted_counts(&self.reasons, usize::MAX) {
+                writeln!(out, "  {code:<24} {count}")?;
+            }
+        }
+        if !self.invalid_domains.is_empty() && top > 0 {
+            writeln!(out, "\nTop invalid domains:")?;
+            for (domain, count) in sorted_counts(&self.invalid_domains, top) {
+                writeln!(out, "  {domain:<24} {count}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Most frequent keys first, ties broken by key.
+fn sorted_counts<K: Ord>(counts: &HashMap<K, usize>, limit: usize) -> Vec<(&K, usize)> {
+    let mut sorted: Vec<_> = counts.iter().map(|(key, &count)| (key, count)).collect();
+    sorted.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    sorted.truncate(limit);
+    sorted
+}
+
+/// Derives `dir/stem.suffix.txt` from the input path, or uses `stdin` as the
+/// stem when reading standard input.
+fn default_output(input: &Path, suffix: &str) -> PathBuf {
+    let stem = if input == Path::new("-") {
+        "stdin".into()
+    } else {
+        input
+            .file_stem()
+            .map_or_else(|| "emails".into(), |stem| stem.to_string_lossy())
+    };
+    input.with_file_name(format!("{stem}.{suffix}.txt"))
+}
+
+fn create_output(path: &Path) -> io::Result<BufWriter<File>> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))
+}
+
+fn clean(options: &CleanOptions) -> io::Result<()> {
+    let reader: Box<dyn BufRead> = if options.input == Path::new("-") {
+        Box::new(io::stdin().lock())
+    } else {
+        let file = File::open(&options.input).map_err(|err| {
+            io::Error::new(err.kind(), format!("{}: {err}", options.input.display()))
+        })?;
+        Box::new(BufReader::new(file))
+    };
+
+    let output = |path: &Option<PathBuf>, suffix| {
+        create_output(
+            &path
+                .clone()
+                .unwrap_or_else(|| default_output(&options.input, suffix)),
+        )
+    };
+    let mut valid = output(&options.valid, "valid")?;
+    let mut invalid = output(&options.invalid, "invalid")?;
+    let mut duplicates = output(&options.duplicates, "duplicates")?;
+
+    let mut summary = CleanSummary::default();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut stream = EmailStream::with_policy(reader, options.policy);
+
+    while let Some(entry) = stream.next_entry() {
+        let (line_no, input, result) = entry?;
+        if input.trim_ascii().is_empty() {
+            continue;
+        }
+        summary.lines += 1;
+
+        match result {
+            Ok(address) => {
+                let canonical = address.canonical();
+                if let Some(&first) = seen.get(&canonical) {
+                    summary.duplicates += 1;
+                    duplicates.write_all(format!("{line_no}\t").as_bytes())?;
+                    duplicates.write_all(input)?;
+                    writeln!(duplicates, "\t{first}")?;
+                    continue;
+                }
+
+                summary.valid += 1;
+                summary.idn += usize::from(address.is_idn());
+                summary.ip_literals += usize::from(address.is_ip_literal());
+                writeln!(valid, "{canonical}")?;
+                seen.insert(canonical, line_no);
+            }
+            Err(err) => {
+                summary.invalid += 1;
+                *summary.reasons.entry(err.code()).or_default() += 1;
+                if let Some(at) = input.iter().rposition(|&byte| byte == b'@') {
+                    let domain = String::from_utf8_lossy(&input[at + 1..]).to_lowercase();
+                    if !domain.is_empty() {
+                        *summary.invalid_domains.entry(domain).or_default() += 1;
+                    }
+                }
+
+                invalid.write_all(format!("{line_no}\t").as_bytes())?;
+                invalid.write_all(input)?;
+                writeln!(invalid, "\t{}", err.code())?;
+            }
+        }
+    }
+
+    valid.flush()?;
+    invalid.flush()?;
+    duplicates.flush()?;
+
+    let mut stdout = io::stdout().lock();
+    summary.write_to(&mut stdout, options.top)?;
+    stdout.flush()
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::Clean(options)) => {
+            return match clean(&options) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    ExitCode::from(2)
+                }
+            };
+        }
+        Ok(Command::Help) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Ok(Command::Version) => {
+            println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(options) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
     }
 }
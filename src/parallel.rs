@@ -0,0 +1,99 @@
+//! Parallel validation on top of `rayon`, enabled with the `rayon` feature.
+
+use crate::address::EmailAddress;
+use crate::error::EmailValidationError;
+use crate::policy::ValidationPolicy;
+use crate::validator::EmailValidator;
+use rayon::prelude::*;
+
+/// Validates every address in `emails` across the rayon thread pool,
+/// returning one flag per input in the original order.
+#[inline]
+#[must_use]
+pub fn par_validate<S: AsRef<str> + Sync>(emails: &[S]) -> Vec<bool> {
+    par_validate_with_policy(emails, ValidationPolicy::DEFAULT)
+}
+
+#[must_use]
+pub fn par_validate_with_policy<S: AsRef<str> + Sync>(
+    emails: &[S],
+    policy: ValidationPolicy,
+) -> Vec<bool> {
+    emails
+        .par_iter()
+        .map(|email| EmailValidator::validate_with_policy(email.as_ref().as_bytes(), policy))
+        .collect()
+}
+
+/// Parses every address in `emails` in parallel, keeping the error reason
+/// for each rejected input.
+#[inline]
+#[must_use]
+pub fn par_parse<S: AsRef<str> + Sync>(
+    emails: &[S],
+) -> Vec<Result<EmailAddress<'_>, EmailValidationError>> {
+    par_parse_with_policy(emails, ValidationPolicy::DEFAULT)
+}
+
+#[must_use]
+pub fn par_parse_with_policy<S: AsRef<str> + Sync>(
+    emails: &[S],
+    policy: ValidationPolicy,
+) -> Vec<Result<EmailAddress<'_>, EmailValidationError>> {
+    emails
+        .par_iter()
+        .map(|email| EmailValidator::parse_with_policy(email.as_ref().as_bytes(), policy))
+        .collect()
+}
+
+/// Adapters for validating the items of any [`ParallelIterator`], e.g. rows
+/// streamed out of `par_lines()` or a `par_bridge()`.
+///
+/// Items are passed through alongside their result so callers can route
+/// them without a second lookup.
+pub trait ParallelValidateEmail: ParallelIterator
+where
+    Self::Item: AsRef<str>,
+{
+    #[inline]
+    fn validate_emails(self) -> impl ParallelIterator<Item = (Self::Item, bool)> {
+        self.validate_emails_with_policy(ValidationPolicy::DEFAULT)
+    }
+
+    #[inline]
+    fn validate_emails_with_policy(
+        self,
+        policy: ValidationPolicy,
+    ) -> impl ParallelIterator<Item = (Self::Item, bool)> {
+        self.map(move |email| {
+            let valid = EmailValidator::validate_with_policy(email.as_ref().as_bytes(), policy);
+            (email, valid)
+        })
+    }
+
+    #[inline]
+    fn check_emails(
+        self,
+    ) -> impl ParallelIterator<Item = (Self::Item, Result<(), EmailValidationError>)> {
+        self.check_emails_with_policy(ValidationPolicy::DEFAULT)
+    }
+
+    #[inline]
+    fn check_emails_with_policy(
+        self,
+        policy: ValidationPolicy,
+    ) -> impl ParallelIterator<Item = (Self::Item, Result<(), EmailValidationError>)> {
+        self.map(move |email| {
+            let result =
+                EmailValidator::parse_with_policy(email.as_ref().as_bytes(), policy).map(|_| ());
+            (email, result)
+        })
+    }
+}
+
+impl<I> ParallelValidateEmail for I
+where
+    I: ParallelIterator,
+    I::Item: AsRef<str>,
+{
+}
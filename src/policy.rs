@@ -0,0 +1,54 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValidationPolicy {
+    allow_ip_zone_id: bool,
+    strip_source_route: bool,
+}
+
+impl ValidationPolicy {
+    pub const DEFAULT: Self = Self::new();
+
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            allow_ip_zone_id: false,
+            strip_source_route: true,
+        }
+    }
+
+    /// Accept RFC 6874 style zone identifiers in IPv6 literals, e.g. `[IPv6:fe80::1%eth0]`.
+    #[inline]
+    #[must_use]
+    pub const fn allow_ip_zone_id(mut self, allow: bool) -> Self {
+        self.allow_ip_zone_id = allow;
+        self
+    }
+
+    /// Silently drop deprecated SMTP source routes such as `<@a,@b:user@c>`
+    /// (the RFC 5321 recommendation) instead of rejecting the path.
+    #[inline]
+    #[must_use]
+    pub const fn strip_source_route(mut self, strip: bool) -> Self {
+        self.strip_source_route = strip;
+        self
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn allows_ip_zone_id(&self) -> bool {
+        self.allow_ip_zone_id
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn strips_source_route(&self) -> bool {
+        self.strip_source_route
+    }
+}
+
+impl Default for ValidationPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,130 @@
+//! Serde support, enabled with the `serde` feature.
+//!
+//! [`Email`] serializes as a plain string and only deserializes from a valid
+//! address. Fields kept as `String` can be validated the same way with
+//! `#[serde(with = "email_syntax_verify_opt::serde::string")]`.
+
+use crate::email::Email;
+use crate::error::EmailValidationError;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+use crate::error::{EmailFieldError, EmailFieldErrors};
+use ::serde::de::{self, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::RefCell;
+use std::fmt;
+
+thread_local! {
+    /// Addresses rejected by [`EmailVisitor`] while a web extractor is
+    /// deserializing, so they can be reported like invalid fields.
+    static REJECTED: RefCell<Option<Vec<(String, EmailValidationError)>>> =
+        const { RefCell::new(None) };
+}
+
+/// Restores the previous collection state, even if deserializing panics.
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+struct Collecting(Option<Vec<(String, EmailValidationError)>>);
+
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+impl Drop for Collecting {
+    fn drop(&mut self) {
+        REJECTED.set(self.0.take());
+    }
+}
+
+/// Runs `deserialize`, appending the addresses [`Email`] values rejected
+/// along the way to `rejected`.
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+pub(crate) fn collect_rejected<R>(
+    rejected: &mut Vec<(String, EmailValidationError)>,
+    deserialize: impl FnOnce() -> R,
+) -> R {
+    let guard = Collecting(REJECTED.replace(Some(Vec::new())));
+    let result = deserialize();
+    rejected.extend(REJECTED.take().unwrap_or_default());
+    drop(guard);
+    result
+}
+
+/// Names each rejected address after the first `(name, value)` parameter
+/// holding it.
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+pub(crate) fn rejected_fields(
+    rejected: Vec<(String, EmailValidationError)>,
+    params: &[(&str, &str)],
+) -> Result<(), EmailFieldErrors> {
+    let errors = rejected
+        .into_iter()
+        .map(|(value, error)| {
+            let name = params
+                .iter()
+                .find_map(|&(name, param)| (param == value).then_some(name));
+            EmailFieldError::new(name.unwrap_or_default(), error)
+        })
+        .collect();
+    EmailFieldErrors::check(errors)
+}
+
+#[cold]
+#[inline(never)]
+fn rejected<E: de::Error>(value: &str, err: EmailValidationError) -> E {
+    REJECTED.with_borrow_mut(|rejected| {
+        if let Some(rejected) = rejected {
+            rejected.push((value.to_owned(), err));
+        }
+    });
+    E::custom(format_args!("invalid email address: {err}"))
+}
+
+impl Serialize for Email {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Email {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_string(EmailVisitor)
+    }
+}
+
+struct EmailVisitor;
+
+impl Visitor<'_> for EmailVisitor {
+    type Value = Email;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an email address")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Email, E> {
+        Email::parse(value).map_err(|err| rejected(value, err))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Email, E> {
+        Email::parse_owned(value).map_err(|(value, err)| rejected(&value, err))
+    }
+}
+
+/// `#[serde(with = ...)]` helpers validating a `String` field as an address.
+pub mod string {
+    use super::{Deserializer, EmailVisitor, Serializer};
+
+    /// Writes the field unchanged.
+    #[inline]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<str> + ?Sized,
+        S: Serializer,
+    {
+        serializer.serialize_str(value.as_ref())
+    }
+
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        deserializer
+            .deserialize_string(EmailVisitor)
+            .map(String::from)
+    }
+}
@@ -0,0 +1,583 @@
+//! Byte-class scanning for the validator hot paths.
+//!
+//! On `x86_64` inputs of at least 16 bytes are processed with SSE2, and with
+//! AVX2 in [`SIMD_CHUNK_SIZE`] blocks when the CPU supports it (detected at
+//! runtime with `std`, or enabled at compile time without it). Shorter inputs
+//! and other targets use the portable scalar code.
+
+use crate::constants::{ASCII_MASK, SIMD_CHUNK_SIZE};
+use crate::validator::EmailValidator;
+
+#[cfg(target_arch = "x86_64")]
+const SSE2_WIDTH: usize = 16;
+
+/// Number of addresses [`Dispatch::classify_short`] looks at per call.
+pub(crate) const BATCH_LANES: usize = 4;
+
+/// Byte classes of one address of at most [`SIMD_CHUNK_SIZE`] bytes, where
+/// bit `i` of each mask describes byte `i`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ByteClasses {
+    pub at: u32,
+    pub dot: u32,
+    pub hyphen: u32,
+    pub user: u32,
+    pub domain: u32,
+}
+
+/// The instruction set used for scanning, detected once so that callers
+/// running many scans, such as batch validation, pay for the feature check
+/// once rather than on every scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dispatch {
+    #[cfg_attr(not(target_arch = "x86_64"), allow(dead_code))]
+    avx2: bool,
+}
+
+impl Dispatch {
+    #[inline]
+    #[must_use]
+    #[cfg_attr(not(feature = "std"), allow(clippy::missing_const_for_fn))]
+    pub fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        let avx2 = x86::has_avx2();
+        #[cfg(not(target_arch = "x86_64"))]
+        let avx2 = false;
+
+        Self { avx2 }
+    }
+
+    /// Position of the first `needle` in `haystack`.
+    #[inline]
+    #[must_use]
+    pub fn find_byte(self, haystack: &[u8], needle: u8) -> Option<usize> {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if haystack.len() >= SIMD_CHUNK_SIZE && self.avx2 {
+                // SAFETY: AVX2 support was checked by `detect`.
+                return unsafe { x86::avx2::find_byte(haystack, needle) };
+            }
+            if haystack.len() >= SSE2_WIDTH {
+                // SAFETY: SSE2 is part of the x86_64 baseline.
+                return unsafe { x86::sse2::find_byte(haystack, needle) };
+            }
+        }
+
+        portable::find_byte(haystack, needle)
+    }
+
+    /// Position of the last `needle` in `haystack`.
+    #[inline]
+    #[must_use]
+    pub fn rfind_byte(self, haystack: &[u8], needle: u8) -> Option<usize> {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if haystack.len() >= SIMD_CHUNK_SIZE && self.avx2 {
+                // SAFETY: AVX2 support was checked by `detect`.
+                return unsafe { x86::avx2::rfind_byte(haystack, needle) };
+            }
+            if haystack.len() >= SSE2_WIDTH {
+                // SAFETY: SSE2 is part of the x86_64 baseline.
+                return unsafe { x86::sse2::rfind_byte(haystack, needle) };
+            }
+        }
+
+        portable::rfind_byte(haystack, needle)
+    }
+
+    /// Whether every byte is an unquoted local-part character (`atext` or `.`).
+    #[inline]
+    #[must_use]
+    pub fn all_user_chars(self, bytes: &[u8]) -> bool {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if bytes.len() >= SIMD_CHUNK_SIZE && self.avx2 {
+                // SAFETY: AVX2 support was checked by `detect`.
+                return unsafe { x86::avx2::all_user_chars(bytes) };
+            }
+            if bytes.len() >= SSE2_WIDTH {
+                // SAFETY: SSE2 is part of the x86_64 baseline.
+                return unsafe { x86::sse2::all_user_chars(bytes) };
+            }
+        }
+
+        portable::all_user_chars(bytes)
+    }
+
+    /// Whether every byte is an ASCII letter, digit, `-` or `.`, i.e. the
+    /// domain can be checked without IDN processing.
+    #[inline]
+    #[must_use]
+    pub fn all_domain_chars(self, bytes: &[u8]) -> bool {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if bytes.len() >= SIMD_CHUNK_SIZE && self.avx2 {
+                // SAFETY: AVX2 support was checked by `detect`.
+                return unsafe { x86::avx2::all_domain_chars(bytes) };
+            }
+            if bytes.len() >= SSE2_WIDTH {
+                // SAFETY: SSE2 is part of the x86_64 baseline.
+                return unsafe { x86::sse2::all_domain_chars(bytes) };
+            }
+        }
+
+        portable::all_domain_chars(bytes)
+    }
+
+    /// Whether `bytes` contains two consecutive dots.
+    #[inline]
+    #[must_use]
+    pub fn has_dot_run(self, bytes: &[u8]) -> bool {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if bytes.len() > SIMD_CHUNK_SIZE && self.avx2 {
+                // SAFETY: AVX2 support was checked by `detect`.
+                return unsafe { x86::avx2::has_dot_run(bytes) };
+            }
+            if bytes.len() > SSE2_WIDTH {
+                // SAFETY: SSE2 is part of the x86_64 baseline.
+                return unsafe { x86::sse2::has_dot_run(bytes) };
+            }
+        }
+
+        portable::has_dot_run(bytes)
+    }
+
+    /// Classifies the bytes of several short addresses at once, one AVX2
+    /// register per address, so their scans overlap instead of running one
+    /// after the other. Addresses longer than [`SIMD_CHUNK_SIZE`] bytes come
+    /// back with every mask empty. Returns `None` without AVX2.
+    #[inline]
+    pub(crate) fn classify_short(
+        self,
+        emails: [&[u8]; BATCH_LANES],
+    ) -> Option<[ByteClasses; BATCH_LANES]> {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if self.avx2 {
+                // SAFETY: AVX2 support was checked by `detect`.
+                return Some(unsafe { x86::avx2::classify_short(emails) });
+            }
+        }
+
+        let _ = emails;
+        None
+    }
+}
+
+/// Position of the first `needle` in `haystack`.
+#[inline]
+#[must_use]
+pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    Dispatch::detect().find_byte(haystack, needle)
+}
+
+/// Position of the last `needle` in `haystack`.
+#[inline]
+#[must_use]
+pub fn rfind_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    Dispatch::detect().rfind_byte(haystack, needle)
+}
+
+/// Whether every byte is an unquoted local-part character (`atext` or `.`).
+#[inline]
+#[must_use]
+pub fn all_user_chars(bytes: &[u8]) -> bool {
+    Dispatch::detect().all_user_chars(bytes)
+}
+
+/// Whether every byte is an ASCII letter, digit, `-` or `.`, i.e. the
+/// domain can be checked without IDN processing.
+#[inline]
+#[must_use]
+pub fn all_domain_chars(bytes: &[u8]) -> bool {
+    Dispatch::detect().all_domain_chars(bytes)
+}
+
+/// Whether `bytes` contains two consecutive dots.
+#[inline]
+#[must_use]
+pub fn has_dot_run(bytes: &[u8]) -> bool {
+    Dispatch::detect().has_dot_run(bytes)
+}
+
+mod portable {
+    use super::{EmailValidator, ASCII_MASK};
+
+    #[inline]
+    pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+        for (pos, &byte) in haystack.iter().enumerate() {
+            if byte == needle {
+                return Some(pos);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    pub fn rfind_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+        let mut pos = haystack.len();
+        while pos > 0 {
+            pos -= 1;
+            if haystack[pos] == needle {
+                return Some(pos);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    pub fn all_user_chars(bytes: &[u8]) -> bool {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in chunks.by_ref() {
+            let mut word = [0; 8];
+            word.copy_from_slice(chunk);
+            if u64::from_ne_bytes(word) & ASCII_MASK != 0 {
+                return false;
+            }
+            for &byte in chunk {
+                if !EmailValidator::is_user_char(byte) {
+                    return false;
+                }
+            }
+        }
+
+        for &byte in chunks.remainder() {
+            if !EmailValidator::is_user_char(byte) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    pub fn all_domain_chars(bytes: &[u8]) -> bool {
+        for &byte in bytes {
+            if byte != b'.' && !EmailValidator::is_domain_char(byte) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    pub fn has_dot_run(bytes: &[u8]) -> bool {
+        let mut prev_was_dot = false;
+        for &byte in bytes {
+            let is_dot = byte == b'.';
+            if is_dot && prev_was_dot {
+                return true;
+            }
+            prev_was_dot = is_dot;
+        }
+        false
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    //! Signed byte comparisons are used for the range checks, so bytes at or
+    //! above 0x80 compare as negative and fall outside every ASCII class.
+
+    /// Whether the AVX2 paths may run: detected at runtime with `std`, and
+    /// only when compiled in via `target-feature` without it.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    pub fn has_avx2() -> bool {
+        std::arch::is_x86_feature_detected!("avx2")
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline(always)]
+    pub const fn has_avx2() -> bool {
+        cfg!(target_feature = "avx2")
+    }
+
+    pub mod sse2 {
+        use super::super::SSE2_WIDTH;
+        use core::arch::x86_64::{
+            __m128i, _mm_and_si128, _mm_cmpeq_epi8, _mm_cmpgt_epi8, _mm_cmplt_epi8,
+            _mm_loadu_si128, _mm_movemask_epi8, _mm_or_si128, _mm_set1_epi8,
+        };
+
+        const FULL_MASK: u32 = (1 << SSE2_WIDTH) - 1;
+
+        #[inline]
+        #[target_feature(enable = "sse2")]
+        fn load(bytes: &[u8], offset: usize) -> __m128i {
+            let chunk = &bytes[offset..offset + SSE2_WIDTH];
+            // SAFETY: `chunk` is exactly 16 readable bytes and the load is unaligned.
+            unsafe { _mm_loadu_si128(chunk.as_ptr().cast()) }
+        }
+
+        #[inline]
+        #[target_feature(enable = "sse2")]
+        fn splat(byte: u8) -> __m128i {
+            _mm_set1_epi8(byte.cast_signed())
+        }
+
+        #[inline]
+        #[target_feature(enable = "sse2")]
+        fn mask(v: __m128i) -> u32 {
+            _mm_movemask_epi8(v).cast_unsigned()
+        }
+
+        #[inline]
+        #[target_feature(enable = "sse2")]
+        fn in_range(v: __m128i, lo: u8, hi: u8) -> __m128i {
+            _mm_and_si128(
+                _mm_cmpgt_epi8(v, splat(lo - 1)),
+                _mm_cmplt_epi8(v, splat(hi + 1)),
+            )
+        }
+
+        #[inline]
+        #[target_feature(enable = "sse2")]
+        fn user_class(v: __m128i) -> __m128i {
+            let mut class = _mm_cmpeq_epi8(v, splat(b'!'));
+            class = _mm_or_si128(class, in_range(v, b'#', b'\''));
+            class = _mm_or_si128(class, in_range(v, b'*', b'+'));
+            class = _mm_or_si128(class, in_range(v, b'-', b'9'));
+            class = _mm_or_si128(class, _mm_cmpeq_epi8(v, splat(b'=')));
+            class = _mm_or_si128(class, _mm_cmpeq_epi8(v, splat(b'?')));
+            class = _mm_or_si128(class, in_range(v, b'A', b'Z'));
+            _mm_or_si128(class, in_range(v, b'^', b'~'))
+        }
+
+        #[inline]
+        #[target_feature(enable = "sse2")]
+        fn domain_class(v: __m128i) -> __m128i {
+            let mut class = in_range(v, b'-', b'.');
+            class = _mm_or_si128(class, in_range(v, b'0', b'9'));
+            class = _mm_or_si128(class, in_range(v, b'A', b'Z'));
+            _mm_or_si128(class, in_range(v, b'a', b'z'))
+        }
+
+        /// Offsets of every 16-byte block, the last one overlapping its
+        /// predecessor when the length is not a multiple of 16.
+        #[inline]
+        fn block_offsets(len: usize) -> impl Iterator<Item = usize> {
+            let last = len - SSE2_WIDTH;
+            (0..last).step_by(SSE2_WIDTH).chain(core::iter::once(last))
+        }
+
+        #[target_feature(enable = "sse2")]
+        pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+            let needle = splat(needle);
+            for offset in block_offsets(haystack.len()) {
+                let hits = mask(_mm_cmpeq_epi8(load(haystack, offset), needle));
+                if hits != 0 {
+                    return Some(offset + hits.trailing_zeros() as usize);
+                }
+            }
+            None
+        }
+
+        #[target_feature(enable = "sse2")]
+        pub fn rfind_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+            let needle = splat(needle);
+            let mut end = haystack.len();
+
+            while end > 0 {
+                let offset = end.saturating_sub(SSE2_WIDTH);
+                let hits = mask(_mm_cmpeq_epi8(load(haystack, offset), needle));
+                // Only lanes before `end` are new in an overlapping first block.
+                let hits = hits & (FULL_MASK >> (SSE2_WIDTH - (end - offset)));
+                if hits != 0 {
+                    return Some(offset + hits.ilog2() as usize);
+                }
+                end = offset;
+            }
+
+            None
+        }
+
+        #[target_feature(enable = "sse2")]
+        pub fn all_user_chars(bytes: &[u8]) -> bool {
+            for offset in block_offsets(bytes.len()) {
+                if mask(user_class(load(bytes, offset))) != FULL_MASK {
+                    return false;
+                }
+            }
+            true
+        }
+
+        #[target_feature(enable = "sse2")]
+        pub fn all_domain_chars(bytes: &[u8]) -> bool {
+            for offset in block_offsets(bytes.len()) {
+                if mask(domain_class(load(bytes, offset))) != FULL_MASK {
+                    return false;
+                }
+            }
+            true
+        }
+
+        #[target_feature(enable = "sse2")]
+        pub fn has_dot_run(bytes: &[u8]) -> bool {
+            let dot = splat(b'.');
+            for offset in block_offsets(bytes.len() - 1) {
+                let here = _mm_cmpeq_epi8(load(bytes, offset), dot);
+                let next = _mm_cmpeq_epi8(load(bytes, offset + 1), dot);
+                if mask(_mm_and_si128(here, next)) != 0 {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+
+    pub mod avx2 {
+        use super::super::{ByteClasses, BATCH_LANES, SIMD_CHUNK_SIZE};
+        use core::arch::x86_64::{
+            __m256i, _mm256_and_si256, _mm256_cmpeq_epi8, _mm256_cmpgt_epi8, _mm256_loadu_si256,
+            _mm256_movemask_epi8, _mm256_or_si256, _mm256_set1_epi8,
+        };
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        fn load(bytes: &[u8], offset: usize) -> __m256i {
+            let chunk = &bytes[offset..offset + SIMD_CHUNK_SIZE];
+            // SAFETY: `chunk` is exactly 32 readable bytes and the load is unaligned.
+            unsafe { _mm256_loadu_si256(chunk.as_ptr().cast()) }
+        }
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        fn splat(byte: u8) -> __m256i {
+            _mm256_set1_epi8(byte.cast_signed())
+        }
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        fn mask(v: __m256i) -> u32 {
+            _mm256_movemask_epi8(v).cast_unsigned()
+        }
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        fn in_range(v: __m256i, lo: u8, hi: u8) -> __m256i {
+            _mm256_and_si256(
+                _mm256_cmpgt_epi8(v, splat(lo - 1)),
+                _mm256_cmpgt_epi8(splat(hi + 1), v),
+            )
+        }
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        fn user_class(v: __m256i) -> __m256i {
+            let mut class = _mm256_cmpeq_epi8(v, splat(b'!'));
+            class = _mm256_or_si256(class, in_range(v, b'#', b'\''));
+            class = _mm256_or_si256(class, in_range(v, b'*', b'+'));
+            class = _mm256_or_si256(class, in_range(v, b'-', b'9'));
+            class = _mm256_or_si256(class, _mm256_cmpeq_epi8(v, splat(b'=')));
+            class = _mm256_or_si256(class, _mm256_cmpeq_epi8(v, splat(b'?')));
+            class = _mm256_or_si256(class, in_range(v, b'A', b'Z'));
+            _mm256_or_si256(class, in_range(v, b'^', b'~'))
+        }
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        fn domain_class(v: __m256i) -> __m256i {
+            let mut class = in_range(v, b'-', b'.');
+            class = _mm256_or_si256(class, in_range(v, b'0', b'9'));
+            class = _mm256_or_si256(class, in_range(v, b'A', b'Z'));
+            _mm256_or_si256(class, in_range(v, b'a', b'z'))
+        }
+
+        #[inline]
+        fn block_offsets(len: usize) -> impl Iterator<Item = usize> {
+            let last = len - SIMD_CHUNK_SIZE;
+            (0..last)
+                .step_by(SIMD_CHUNK_SIZE)
+                .chain(core::iter::once(last))
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+            let needle = splat(needle);
+            for offset in block_offsets(haystack.len()) {
+                let hits = mask(_mm256_cmpeq_epi8(load(haystack, offset), needle));
+                if hits != 0 {
+                    return Some(offset + hits.trailing_zeros() as usize);
+                }
+            }
+            None
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub fn rfind_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+            let needle = splat(needle);
+            let mut end = haystack.len();
+
+            while end > 0 {
+                let offset = end.saturating_sub(SIMD_CHUNK_SIZE);
+                let hits = mask(_mm256_cmpeq_epi8(load(haystack, offset), needle));
+                // Only lanes before `end` are new in an overlapping first block.
+                let hits = hits & (u32::MAX >> (SIMD_CHUNK_SIZE - (end - offset)));
+                if hits != 0 {
+                    return Some(offset + hits.ilog2() as usize);
+                }
+                end = offset;
+            }
+
+            None
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub fn all_user_chars(bytes: &[u8]) -> bool {
+            for offset in block_offsets(bytes.len()) {
+                if mask(user_class(load(bytes, offset))) != u32::MAX {
+                    return false;
+                }
+            }
+            true
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub fn all_domain_chars(bytes: &[u8]) -> bool {
+            for offset in block_offsets(bytes.len()) {
+                if mask(domain_class(load(bytes, offset))) != u32::MAX {
+                    return false;
+                }
+            }
+            true
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub fn classify_short(emails: [&[u8]; BATCH_LANES]) -> [ByteClasses; BATCH_LANES] {
+            // Zero padding belongs to no class, so masks stop at each length.
+            let mut blocks = [[0; SIMD_CHUNK_SIZE]; BATCH_LANES];
+            for (block, email) in blocks.iter_mut().zip(emails) {
+                if email.len() <= SIMD_CHUNK_SIZE {
+                    block[..email.len()].copy_from_slice(email);
+                }
+            }
+
+            let mut classes = [ByteClasses::default(); BATCH_LANES];
+            for (class, block) in classes.iter_mut().zip(&blocks) {
+                let v = load(block, 0);
+                *class = ByteClasses {
+                    at: mask(_mm256_cmpeq_epi8(v, splat(b'@'))),
+                    dot: mask(_mm256_cmpeq_epi8(v, splat(b'.'))),
+                    hyphen: mask(_mm256_cmpeq_epi8(v, splat(b'-'))),
+                    user: mask(user_class(v)),
+                    domain: mask(domain_class(v)),
+                };
+            }
+            classes
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub fn has_dot_run(bytes: &[u8]) -> bool {
+            let dot = splat(b'.');
+            for offset in block_offsets(bytes.len() - 1) {
+                let here = _mm256_cmpeq_epi8(load(bytes, offset), dot);
+                let next = _mm256_cmpeq_epi8(load(bytes, offset + 1), dot);
+                if mask(_mm256_and_si256(here, next)) != 0 {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+}
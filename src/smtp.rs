@@ -0,0 +1,267 @@
+use crate::address::EmailAddress;
+use crate::constants::MAX_SMTP_PATH_LENGTH;
+use crate::error::SmtpPathError;
+use crate::policy::ValidationPolicy;
+use crate::validator::EmailValidator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmtpCommand {
+    MailFrom,
+    RcptTo,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmtpPath<'a> {
+    /// The null reverse-path `<>`, used for bounces.
+    Null,
+    /// The bare `<Postmaster>` forward-path.
+    Postmaster,
+    Mailbox(EmailAddress<'a>),
+}
+
+impl<'a> SmtpPath<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn mailbox(&self) -> Option<EmailAddress<'a>> {
+        match *self {
+            Self::Mailbox(address) => Some(address),
+            Self::Null | Self::Postmaster => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnvelopeCommand<'a> {
+    command: SmtpCommand,
+    path: SmtpPath<'a>,
+    source_route: Option<&'a str>,
+    parameters: &'a str,
+}
+
+impl<'a> EnvelopeCommand<'a> {
+    /// Parses a `MAIL FROM:<...>` or `RCPT TO:<...>` command line, including
+    /// any trailing ESMTP parameters and CRLF.
+    #[inline]
+    pub fn parse(line: &'a str) -> Result<Self, SmtpPathError> {
+        Self::parse_with_policy(line, ValidationPolicy::DEFAULT)
+    }
+
+    pub fn parse_with_policy(
+        line: &'a str,
+        policy: ValidationPolicy,
+    ) -> Result<Self, SmtpPathError> {
+        let line = line.strip_suffix("\r\n").unwrap_or(line);
+
+        let (command, rest) = if let Some(rest) = strip_prefix_ignore_case(line, "MAIL FROM:") {
+            (SmtpCommand::MailFrom, rest)
+        } else if let Some(rest) = strip_prefix_ignore_case(line, "RCPT TO:") {
+            (SmtpCommand::RcptTo, rest)
+        } else {
+            return Err(SmtpPathError::UnknownCommand);
+        };
+
+        // RFC 5321 has no space after the colon, but many clients send one.
+        let rest = rest.trim_start_matches(' ');
+        if !rest.starts_with('<') {
+            return Err(SmtpPathError::MissingAngleBrackets);
+        }
+
+        let close = rest.find('>').ok_or(SmtpPathError::MissingAngleBrackets)?;
+        let (path, source_route) = parse_path(&rest[..=close], command, policy)?;
+
+        let parameters = &rest[close + 1..];
+        if !parameters.is_empty() && !parameters.starts_with(' ') {
+            return Err(SmtpPathError::InvalidParameter);
+        }
+
+        let parameters = parameters.trim_matches(' ');
+        if !EsmtpParameters::new(parameters).all(|param| param.is_ok()) {
+            return Err(SmtpPathError::InvalidParameter);
+        }
+
+        Ok(Self {
+            command,
+            path,
+            source_route,
+            parameters,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn command(&self) -> SmtpCommand {
+        self.command
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn path(&self) -> SmtpPath<'a> {
+        self.path
+    }
+
+    /// The deprecated source route that was stripped from the path, without
+    /// the trailing `:`, e.g. `@a.example,@b.example`.
+    #[inline]
+    #[must_use]
+    pub const fn source_route(&self) -> Option<&'a str> {
+        self.source_route
+    }
+
+    #[inline]
+    pub fn parameters(&self) -> impl Iterator<Item = (&'a str, Option<&'a str>)> {
+        EsmtpParameters::new(self.parameters).filter_map(Result::ok)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn parameter(&self, keyword: &str) -> Option<Option<&'a str>> {
+        self.parameters()
+            .find(|(name, _)| name.eq_ignore_ascii_case(keyword))
+            .map(|(_, value)| value)
+    }
+}
+
+struct EsmtpParameters<'a> {
+    params: core::str::Split<'a, char>,
+}
+
+impl<'a> EsmtpParameters<'a> {
+    fn new(parameters: &'a str) -> Self {
+        Self {
+            params: parameters.split(' '),
+        }
+    }
+}
+
+impl<'a> Iterator for EsmtpParameters<'a> {
+    type Item = Result<(&'a str, Option<&'a str>), SmtpPathError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let param = self.params.find(|param| !param.is_empty())?;
+        let (keyword, value) = match param.split_once('=') {
+            Some((keyword, value)) => (keyword, Some(value)),
+            None => (param, None),
+        };
+
+        let keyword_valid = keyword
+            .bytes()
+            .next()
+            .is_some_and(|b| b.is_ascii_alphanumeric())
+            && keyword
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-');
+        let value_valid = value.is_none_or(|value| {
+            !value.is_empty()
+                && value
+                    .bytes()
+                    .all(|b| matches!(b, b'!'..=b'<' | b'>'..=b'~'))
+        });
+
+        Some(if keyword_valid && value_valid {
+            Ok((keyword, value))
+        } else {
+            Err(SmtpPathError::InvalidParameter)
+        })
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = input.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &input[prefix.len()..])
+}
+
+fn split_source_route(path: &str) -> Option<(&str, &str)> {
+    let mut in_literal = false;
+
+    for (pos, byte) in path.bytes().enumerate() {
+        match byte {
+            b'[' => in_literal = true,
+            b']' => in_literal = false,
+            b':' if !in_literal => return Some((&path[..pos], &path[pos + 1..])),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn validate_source_route(route: &str, policy: ValidationPolicy) -> Result<(), SmtpPathError> {
+    for hop in route.split(',') {
+        let domain = hop
+            .strip_prefix('@')
+            .ok_or(SmtpPathError::InvalidSourceRoute)?;
+        EmailValidator::parse_domain(domain.as_bytes(), policy)
+            .map_err(|_| SmtpPathError::InvalidSourceRoute)?;
+    }
+
+    Ok(())
+}
+
+fn parse_path(
+    input: &str,
+    command: SmtpCommand,
+    policy: ValidationPolicy,
+) -> Result<(SmtpPath<'_>, Option<&str>), SmtpPathError> {
+    if input.len() > MAX_SMTP_PATH_LENGTH {
+        return Err(SmtpPathError::TooLong);
+    }
+
+    let inner = input
+        .strip_prefix('<')
+        .and_then(|rest| rest.strip_suffix('>'))
+        .ok_or(SmtpPathError::MissingAngleBrackets)?;
+
+    if inner.is_empty() {
+        return match command {
+            SmtpCommand::MailFrom => Ok((SmtpPath::Null, None)),
+            SmtpCommand::RcptTo => Err(SmtpPathError::NullPathNotAllowed),
+        };
+    }
+
+    let (source_route, mailbox) = if inner.starts_with('@') {
+        let (route, mailbox) =
+            split_source_route(inner).ok_or(SmtpPathError::InvalidSourceRoute)?;
+        validate_source_route(route, policy)?;
+        if !policy.strips_source_route() {
+            return Err(SmtpPathError::SourceRouteNotAllowed);
+        }
+        (Some(route), mailbox)
+    } else {
+        (None, inner)
+    };
+
+    if command == SmtpCommand::RcptTo
+        && source_route.is_none()
+        && mailbox.eq_ignore_ascii_case("postmaster")
+    {
+        return Ok((SmtpPath::Postmaster, None));
+    }
+
+    let address = EmailValidator::parse_with_policy(mailbox.as_bytes(), policy)?;
+    Ok((SmtpPath::Mailbox(address), source_route))
+}
+
+/// Parses an angle-bracketed reverse-path such as `<user@host>` or `<>`.
+#[inline]
+pub fn parse_reverse_path(
+    input: &str,
+    policy: ValidationPolicy,
+) -> Result<SmtpPath<'_>, SmtpPathError> {
+    parse_path(input, SmtpCommand::MailFrom, policy).map(|(path, _)| path)
+}
+
+/// Parses an angle-bracketed forward-path such as `<user@host>` or `<Postmaster>`.
+#[inline]
+pub fn parse_forward_path(
+    input: &str,
+    policy: ValidationPolicy,
+) -> Result<SmtpPath<'_>, SmtpPathError> {
+    parse_path(input, SmtpCommand::RcptTo, policy).map(|(path, _)| path)
+}
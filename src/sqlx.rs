@@ -0,0 +1,56 @@
+//! `sqlx` support for [`Email`], enabled with the `sqlx` feature.
+//!
+//! An [`Email`] is stored as text in any database `String` supports, and
+//! decoding fails when the stored value is not a valid address.
+
+use crate::email::Email;
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Decode, Encode, Type};
+
+impl<DB: Database> Type<DB> for Email
+where
+    String: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for Email
+where
+    String: Encode<'q, DB>,
+{
+    #[inline]
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.address.encode_by_ref(buf)
+    }
+
+    #[inline]
+    fn encode(self, buf: &mut <DB as Database>::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.address.encode(buf)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.address.size_hint()
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for Email
+where
+    String: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(Self::try_from(String::decode(value)?)?)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::address::EmailAddress;
+use crate::constants::MAX_EMAIL_LENGTH;
+use crate::error::EmailValidationError;
+use crate::policy::ValidationPolicy;
+use crate::simd;
+use crate::validator::EmailValidator;
+use std::io::{self, BufRead};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Bytes kept per line: enough for a byte order mark, the longest address and
+/// a `\r`, and one more so overlong lines are still recognized after
+/// truncation.
+const LINE_BUFFER_LENGTH: usize = UTF8_BOM.len() + MAX_EMAIL_LENGTH + 2;
+
+/// A 1-based line number, the line without its line ending, and the result
+/// of validating it.
+pub type StreamEntry<'a> = (
+    usize,
+    &'a [u8],
+    Result<EmailAddress<'a>, EmailValidationError>,
+);
+
+/// Validates newline-delimited addresses read from any [`BufRead`].
+///
+/// Lines are copied into a single reused buffer capped just above
+/// [`MAX_EMAIL_LENGTH`], so memory stays bounded however large the input or
+/// its longest line. Both `\n` and `\r\n` endings are accepted and a leading
+/// UTF-8 byte order mark is skipped. Blank lines are reported as
+/// [`EmailValidationError::Empty`].
+///
+/// Entries borrow the internal buffer, so the stream is driven with
+/// `while let Some(entry) = stream.next_entry()` rather than [`Iterator`].
+#[derive(Debug)]
+pub struct EmailStream<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    line_no: usize,
+    truncated: bool,
+    policy: ValidationPolicy,
+}
+
+impl<R: BufRead> EmailStream<R> {
+    #[inline]
+    #[must_use]
+    pub fn new(reader: R) -> Self {
+        Self::with_policy(reader, ValidationPolicy::DEFAULT)
+    }
+
+    #[must_use]
+    pub fn with_policy(reader: R, policy: ValidationPolicy) -> Self {
+        Self {
+            reader,
+            buffer: Vec::with_capacity(LINE_BUFFER_LENGTH),
+            line_no: 0,
+            truncated: false,
+            policy,
+        }
+    }
+
+    /// Reads and validates the next line, or returns `None` at end of input.
+    ///
+    /// A line longer than the buffer is reported as
+    /// [`EmailValidationError::TooLong`] with only its first bytes as input.
+    pub fn next_entry(&mut self) -> Option<io::Result<StreamEntry<'_>>> {
+        match self.read_line() {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(err) => return Some(Err(err)),
+        }
+        self.line_no += 1;
+
+        let mut line = self.buffer.as_slice();
+        if self.line_no == 1 {
+            line = line.strip_prefix(UTF8_BOM).unwrap_or(line);
+        }
+        if !self.truncated {
+            line = line.strip_suffix(b"\r").unwrap_or(line);
+        }
+
+        let result = if self.truncated {
+            Err(EmailValidationError::TooLong)
+        } else {
+            EmailValidator::parse_with_policy(line, self.policy)
+        };
+
+        Some(Ok((self.line_no, line, result)))
+    }
+
+    /// Number of lines read so far.
+    #[inline]
+    #[must_use]
+    pub const fn line_no(&self) -> usize {
+        self.line_no
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_line(&mut self) -> io::Result<bool> {
+        self.buffer.clear();
+        self.truncated = false;
+        let mut read_any = false;
+
+        loop {
+            let available = match self.reader.fill_buf() {
+                Ok(available) => available,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if available.is_empty() {
+                return Ok(read_any);
+            }
+            read_any = true;
+
+            let (line, consumed, done) = simd::find_byte(available, b'\n')
+                .map_or((available, available.len(), false), |newline| {
+                    (&available[..newline], newline + 1, true)
+                });
+
+            let room = LINE_BUFFER_LENGTH - self.buffer.len();
+            if line.len() > room {
+                self.truncated = true;
+            }
+            self.buffer.extend_from_slice(&line[..line.len().min(room)]);
+            self.reader.consume(consumed);
+
+            if done {
+                return Ok(true);
+            }
+        }
+    }
+}
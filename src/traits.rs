@@ -1,7 +1,20 @@
+use crate::error::EmailValidationError;
+#[cfg(feature = "alloc")]
+use crate::error::{EmailFieldError, EmailFieldErrors};
+use crate::policy::ValidationPolicy;
 use crate::validator::EmailValidator;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
 
 pub trait ValidateEmail {
-    fn validate_email(&self) -> bool;
+    /// Whether the address is valid under the default policy.
+    #[inline]
+    fn validate_email(&self) -> bool {
+        self.check_email(ValidationPolicy::DEFAULT).is_ok()
+    }
+
+    /// Validates under `policy`, reporting why the address was rejected.
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError>;
 }
 
 impl ValidateEmail for str {
@@ -9,13 +22,24 @@ impl ValidateEmail for str {
     fn validate_email(&self) -> bool {
         EmailValidator::validate_str(self)
     }
+
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        EmailValidator::parse_with_policy(self.as_bytes(), policy).map(drop)
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl ValidateEmail for String {
     #[inline]
     fn validate_email(&self) -> bool {
         EmailValidator::validate_string(self)
     }
+
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        self.as_str().check_email(policy)
+    }
 }
 
 impl ValidateEmail for &str {
@@ -23,6 +47,11 @@ impl ValidateEmail for &str {
     fn validate_email(&self) -> bool {
         EmailValidator::validate_str(self)
     }
+
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        str::check_email(self, policy)
+    }
 }
 
 impl<T> ValidateEmail for Option<T>
@@ -31,18 +60,30 @@ where
 {
     #[inline]
     fn validate_email(&self) -> bool {
-        self.as_ref().map_or(true, |email| email.validate_email())
+        self.as_ref().is_none_or(ValidateEmail::validate_email)
+    }
+
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        self.as_ref()
+            .map_or(Ok(()), |value| value.check_email(policy))
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> ValidateEmail for Box<T>
 where
-    T: ValidateEmail,
+    T: ValidateEmail + ?Sized,
 {
     #[inline]
     fn validate_email(&self) -> bool {
         T::validate_email(self)
     }
+
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        T::check_email(self, policy)
+    }
 }
 
 impl ValidateEmail for [u8] {
@@ -50,6 +91,11 @@ impl ValidateEmail for [u8] {
     fn validate_email(&self) -> bool {
         EmailValidator::validate(self)
     }
+
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        EmailValidator::parse_with_policy(self, policy).map(drop)
+    }
 }
 
 impl ValidateEmail for &[u8] {
@@ -57,11 +103,75 @@ impl ValidateEmail for &[u8] {
     fn validate_email(&self) -> bool {
         EmailValidator::validate(self)
     }
+
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        EmailValidator::parse_with_policy(self, policy).map(drop)
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl ValidateEmail for Vec<u8> {
     #[inline]
     fn validate_email(&self) -> bool {
         EmailValidator::validate(self)
     }
+
+    #[inline]
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        EmailValidator::parse_with_policy(self, policy).map(drop)
+    }
+}
+
+/// Structs whose address fields can be validated together, usually through
+/// `#[derive(ValidateEmails)]` with the `derive` feature.
+#[cfg(feature = "alloc")]
+pub trait ValidateEmails {
+    fn validate_emails(&self) -> Result<(), EmailFieldErrors>;
+}
+
+/// Checks a lone address under the default policy, reporting it with an
+/// empty path.
+#[cfg(feature = "alloc")]
+pub(crate) fn validate_address<T>(address: &T) -> Result<(), EmailFieldErrors>
+where
+    T: ValidateEmail + ?Sized,
+{
+    match address.check_email(ValidationPolicy::DEFAULT) {
+        Ok(()) => Ok(()),
+        Err(error) => EmailFieldErrors::check(vec![EmailFieldError::new(String::new(), error)]),
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ValidateEmails for String {
+    #[inline]
+    fn validate_emails(&self) -> Result<(), EmailFieldErrors> {
+        validate_address(self)
+    }
+}
+
+/// Runs `check` on every item, tagging the errors it pushes with the item's
+/// `[index]` right after `path`, which those errors must start with.
+///
+/// This is how `#[derive(ValidateEmails)]` reports elements of `Vec`, slice
+/// and array fields, nested collections included.
+#[cfg(feature = "alloc")]
+pub fn check_indexed<'a, T, I>(
+    items: I,
+    path: &str,
+    errors: &mut Vec<EmailFieldError>,
+    mut check: impl FnMut(&'a T, &mut Vec<EmailFieldError>),
+) where
+    T: 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    for (index, item) in items.into_iter().enumerate() {
+        let start = errors.len();
+        check(item, errors);
+        // Only pay for formatting the index once something actually failed.
+        for err in &mut errors[start..] {
+            err.path.insert_str(path.len(), &format!("[{index}]"));
+        }
+    }
 }
@@ -8,17 +8,20 @@ pub enum ValidationResult {
 
 impl ValidationResult {
     #[inline(always)]
+    #[must_use]
     pub const fn is_valid(self) -> bool {
-        matches!(self, ValidationResult::Valid)
+        matches!(self, Self::Valid)
     }
 
     #[inline(always)]
+    #[must_use]
     pub const fn is_invalid(self) -> bool {
-        matches!(self, ValidationResult::Invalid)
+        matches!(self, Self::Invalid)
     }
 
     #[inline(always)]
+    #[must_use]
     pub const fn requires_idn_check(self) -> bool {
-        matches!(self, ValidationResult::RequiresIdnCheck)
+        matches!(self, Self::RequiresIdnCheck)
     }
 }
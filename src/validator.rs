@@ -1,9 +1,19 @@
-use crate::constants::*;
-use crate::ip::{fast_ip_precheck, ValidateIp};
+use crate::address::EmailAddress;
+use crate::constants::{
+    MAX_DOMAIN_LENGTH, MAX_EMAIL_LENGTH, MAX_LABEL_LENGTH, MAX_USER_LENGTH, MIN_EMAIL_LENGTH,
+    SIMD_CHUNK_SIZE,
+};
+use crate::error::EmailValidationError;
+use crate::idn;
+use crate::ip::{parse_ip_literal, IpLiteral};
+use crate::policy::ValidationPolicy;
+use crate::simd::{ByteClasses, Dispatch, BATCH_LANES};
 use crate::types::ValidationResult;
-use idna::domain_to_ascii;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
 
-static USER_CHAR_TABLE: [bool; 256] = {
+#[allow(clippy::cast_possible_truncation)]
+const USER_CHAR_TABLE: [bool; 256] = {
     let mut table = [false; 256];
     let mut i = 0;
     while i < 256 {
@@ -17,7 +27,8 @@ static USER_CHAR_TABLE: [bool; 256] = {
     table
 };
 
-static ALPHANUMERIC_TABLE: [bool; 256] = {
+#[allow(clippy::cast_possible_truncation)]
+const ALPHANUMERIC_TABLE: [bool; 256] = {
     let mut table = [false; 256];
     let mut i = 0;
     while i < 256 {
@@ -27,7 +38,8 @@ static ALPHANUMERIC_TABLE: [bool; 256] = {
     table
 };
 
-static DOMAIN_CHAR_TABLE: [bool; 256] = {
+#[allow(clippy::cast_possible_truncation)]
+const DOMAIN_CHAR_TABLE: [bool; 256] = {
     let mut table = [false; 256];
     let mut i = 0;
     while i < 256 {
@@ -41,23 +53,23 @@ pub struct EmailValidator;
 
 impl EmailValidator {
     #[inline(always)]
-    fn is_user_char(byte: u8) -> bool {
-        unsafe { *USER_CHAR_TABLE.get_unchecked(byte as usize) }
+    pub(crate) const fn is_user_char(byte: u8) -> bool {
+        USER_CHAR_TABLE[byte as usize]
     }
 
     #[inline(always)]
-    fn is_alphanumeric_byte(byte: u8) -> bool {
-        unsafe { *ALPHANUMERIC_TABLE.get_unchecked(byte as usize) }
+    const fn is_alphanumeric_byte(byte: u8) -> bool {
+        ALPHANUMERIC_TABLE[byte as usize]
     }
 
     #[inline(always)]
-    fn is_domain_char(byte: u8) -> bool {
-        unsafe { *DOMAIN_CHAR_TABLE.get_unchecked(byte as usize) }
+    pub(crate) const fn is_domain_char(byte: u8) -> bool {
+        DOMAIN_CHAR_TABLE[byte as usize]
     }
 
     #[cold]
     #[inline(never)]
-    fn validate_user_part_slow_path(bytes: &[u8]) -> bool {
+    const fn validate_user_part_slow_path(bytes: &[u8]) -> bool {
         if bytes.is_empty() {
             return false;
         }
@@ -67,7 +79,10 @@ impl EmailValidator {
         }
 
         let mut prev_was_dot = false;
-        for &byte in bytes {
+        let mut i = 0;
+        while i < bytes.len() {
+            let byte = bytes[i];
+            i += 1;
             if byte > 127 || !Self::is_user_char(byte) {
                 return false;
             }
@@ -86,7 +101,7 @@ impl EmailValidator {
     }
 
     #[inline]
-    fn validate_user_part(bytes: &[u8]) -> bool {
+    fn validate_user_part(bytes: &[u8], dispatch: Dispatch) -> bool {
         let len = bytes.len();
         if len == 0 || len > MAX_USER_LENGTH {
             return false;
@@ -100,76 +115,31 @@ impl EmailValidator {
             return Self::validate_user_part_slow_path(bytes);
         }
 
-        let mut prev_was_dot = false;
-        let (chunks, remainder) = bytes.split_at(len & !7);
-
-        for chunk in chunks.chunks_exact(8) {
-            unsafe {
-                let chunk_u64 = u64::from_ne_bytes(chunk.try_into().unwrap_unchecked());
-                if (chunk_u64 & ASCII_MASK) != 0 {
-                    return false;
-                }
-            }
-
-            for &byte in chunk {
-                if !Self::is_user_char(byte) {
-                    return false;
-                }
-
-                if byte == b'.' {
-                    if prev_was_dot {
-                        return false;
-                    }
-                    prev_was_dot = true;
-                } else {
-                    prev_was_dot = false;
-                }
-            }
-        }
-
-        for &byte in remainder {
-            if byte > 127 || !Self::is_user_char(byte) {
-                return false;
-            }
-
-            if byte == b'.' {
-                if prev_was_dot {
-                    return false;
-                }
-                prev_was_dot = true;
-            } else {
-                prev_was_dot = false;
-            }
-        }
-
-        true
+        dispatch.all_user_chars(bytes) && !dispatch.has_dot_run(bytes)
     }
 
     #[inline]
-    fn validate_domain_label(label: &[u8]) -> ValidationResult {
+    const fn validate_domain_label(label: &[u8]) -> ValidationResult {
         let len = label.len();
 
         if len == 0 || len > MAX_LABEL_LENGTH {
             return ValidationResult::Invalid;
         }
 
-        unsafe {
-            let first = *label.get_unchecked(0);
-            let last = *label.get_unchecked(len - 1);
-
-            if !Self::is_alphanumeric_byte(first) || !Self::is_alphanumeric_byte(last) {
-                return ValidationResult::Invalid;
-            }
-        }
-
-        if len == 1 {
-            return ValidationResult::Valid;
+        // A label may start or end with a non-ASCII character, as in `भारत`;
+        // whether that character is allowed there is left to the IDN check.
+        let (first, last) = (label[0], label[len - 1]);
+        if (first <= 127 && !Self::is_alphanumeric_byte(first))
+            || (last <= 127 && !Self::is_alphanumeric_byte(last))
+        {
+            return ValidationResult::Invalid;
         }
 
-        let middle = unsafe { label.get_unchecked(1..len - 1) };
-        let mut has_non_ascii = false;
-
-        for &byte in middle {
+        let mut has_non_ascii = first > 127 || last > 127;
+        let mut i = 1;
+        while i + 1 < len {
+            let byte = label[i];
+            i += 1;
             if byte > 127 {
                 has_non_ascii = true;
             } else if !Self::is_domain_char(byte) {
@@ -185,52 +155,62 @@ impl EmailValidator {
     }
 
     #[inline]
-    fn validate_domain_part(bytes: &[u8]) -> ValidationResult {
+    const fn is_domain_length_valid(bytes: &[u8]) -> bool {
         let len = bytes.len();
-        if len == 0 || len > MAX_DOMAIN_LENGTH {
-            return ValidationResult::Invalid;
-        }
+        len != 0 && len <= MAX_DOMAIN_LENGTH && bytes[0] != b'.' && bytes[len - 1] != b'.'
+    }
 
-        if bytes[0] == b'.' || bytes[len - 1] == b'.' {
+    #[inline]
+    fn validate_domain_part(bytes: &[u8], dispatch: Dispatch) -> ValidationResult {
+        if !Self::is_domain_length_valid(bytes) {
             return ValidationResult::Invalid;
         }
+        let len = bytes.len();
 
-        let mut start = 0;
-        let mut requires_idn = false;
-        let mut label_count = 0;
-
-        for i in 0..len {
-            unsafe {
-                if *bytes.get_unchecked(i) == b'.' {
-                    if i == start {
+        // Plain ASCII domains only need the label structure checked.
+        if dispatch.all_domain_chars(bytes) {
+            let mut start = 0;
+            for end in 0..=len {
+                if end == len || bytes[end] == b'.' {
+                    if end == start
+                        || end - start > MAX_LABEL_LENGTH
+                        || bytes[start] == b'-'
+                        || bytes[end - 1] == b'-'
+                    {
                         return ValidationResult::Invalid;
                     }
-
-                    let label_result = Self::validate_domain_label(bytes.get_unchecked(start..i));
-                    match label_result {
-                        ValidationResult::Invalid => return ValidationResult::Invalid,
-                        ValidationResult::RequiresIdnCheck => requires_idn = true,
-                        ValidationResult::Valid => {}
-                    }
-                    start = i + 1;
-                    label_count += 1;
+                    start = end + 1;
                 }
             }
+            return ValidationResult::Valid;
         }
 
-        if start < len {
-            let final_label_result =
-                unsafe { Self::validate_domain_label(bytes.get_unchecked(start..)) };
-            match final_label_result {
+        Self::validate_domain_labels(bytes)
+    }
+
+    /// Checks every dot-separated label of a domain whose overall length and
+    /// leading and trailing dots were already checked.
+    const fn validate_domain_labels(bytes: &[u8]) -> ValidationResult {
+        let mut rest = bytes;
+        let mut requires_idn = false;
+
+        loop {
+            let mut end = 0;
+            while end < rest.len() && rest[end] != b'.' {
+                end += 1;
+            }
+
+            let (label, tail) = rest.split_at(end);
+            match Self::validate_domain_label(label) {
                 ValidationResult::Invalid => return ValidationResult::Invalid,
                 ValidationResult::RequiresIdnCheck => requires_idn = true,
                 ValidationResult::Valid => {}
             }
-            label_count += 1;
-        }
 
-        if label_count == 0 {
-            return ValidationResult::Invalid;
+            if tail.is_empty() {
+                break;
+            }
+            rest = tail.split_at(1).1;
         }
 
         if requires_idn {
@@ -241,92 +221,359 @@ impl EmailValidator {
     }
 
     #[inline]
-    fn validate_ip_literal(bytes: &[u8]) -> bool {
+    fn find_last_at_position(bytes: &[u8], dispatch: Dispatch) -> Option<usize> {
         let len = bytes.len();
 
-        if len < 3 || len > 47 {
-            return false;
+        if len < MIN_EMAIL_LENGTH {
+            return None;
         }
 
-        unsafe {
-            if *bytes.get_unchecked(0) != b'[' || *bytes.get_unchecked(len - 1) != b']' {
-                return false;
-            }
+        // The `@` can be neither the first nor the last byte.
+        dispatch
+            .rfind_byte(&bytes[1..len - 1], b'@')
+            .map(|pos| pos + 1)
+    }
 
-            let ip_bytes = bytes.get_unchecked(1..len - 1);
+    #[cold]
+    #[inline(never)]
+    const fn length_error(len: usize) -> EmailValidationError {
+        if len == 0 {
+            EmailValidationError::Empty
+        } else if len < MIN_EMAIL_LENGTH {
+            EmailValidationError::TooShort
+        } else {
+            EmailValidationError::TooLong
+        }
+    }
 
-            if !fast_ip_precheck(ip_bytes) {
-                return false;
-            }
+    #[cold]
+    #[inline(never)]
+    const fn at_symbol_error(bytes: &[u8]) -> EmailValidationError {
+        match (position(bytes, b'@'), rposition(bytes, b'@')) {
+            (None, _) => EmailValidationError::NoAtSymbol,
+            (Some(first), Some(last)) if first != last => EmailValidationError::MultipleAtSymbols,
+            (Some(0), _) => EmailValidationError::InvalidUserPart,
+            _ => EmailValidationError::InvalidDomainPart,
+        }
+    }
 
-            let ip_str = std::str::from_utf8_unchecked(ip_bytes);
-            ip_str.validate_ip()
+    #[cold]
+    #[inline(never)]
+    const fn user_part_error(bytes: &[u8]) -> EmailValidationError {
+        if position(bytes, b'@').is_some() {
+            EmailValidationError::MultipleAtSymbols
+        } else {
+            EmailValidationError::InvalidUserPart
         }
     }
 
-    #[inline]
-    fn find_last_at_position(bytes: &[u8]) -> Option<usize> {
-        let len = bytes.len();
+    #[cold]
+    #[inline(never)]
+    fn parse_domain_literal(
+        bytes: &[u8],
+        policy: ValidationPolicy,
+    ) -> Result<IpLiteral<'_>, EmailValidationError> {
+        if bytes.first() != Some(&b'[') {
+            return Err(EmailValidationError::InvalidDomainPart);
+        }
 
-        if len < MIN_EMAIL_LENGTH {
-            return None;
+        parse_ip_literal(bytes, policy)
+    }
+
+    /// Internationalized domains are checked through their ASCII form, built
+    /// by [`idn::to_ascii`].
+    #[cold]
+    #[inline(never)]
+    fn validate_idn_domain(bytes: &[u8], dispatch: Dispatch) -> Result<(), EmailValidationError> {
+        let domain_str =
+            core::str::from_utf8(bytes).map_err(|_| EmailValidationError::InvalidDomainPart)?;
+
+        match idn::to_ascii(domain_str) {
+            Ok(ascii_domain)
+                if Self::validate_domain_part(ascii_domain.as_bytes(), dispatch).is_valid() =>
+            {
+                Ok(())
+            }
+            _ => Err(EmailValidationError::IdnProcessingFailed),
         }
+    }
 
-        let mut pos = len;
-        while pos > 1 {
-            pos -= 1;
-            unsafe {
-                if *bytes.get_unchecked(pos) == b'@' && pos < len - 1 {
-                    return Some(pos);
-                }
+    /// Validates a domain or bracketed IP literal on its own, returning the
+    /// parsed literal when the domain is one.
+    #[inline]
+    pub fn parse_domain(
+        domain_bytes: &[u8],
+        policy: ValidationPolicy,
+    ) -> Result<Option<IpLiteral<'_>>, EmailValidationError> {
+        Self::parse_domain_with(domain_bytes, policy, Dispatch::detect())
+    }
+
+    #[inline]
+    fn parse_domain_with(
+        domain_bytes: &[u8],
+        policy: ValidationPolicy,
+        dispatch: Dispatch,
+    ) -> Result<Option<IpLiteral<'_>>, EmailValidationError> {
+        match Self::validate_domain_part(domain_bytes, dispatch) {
+            ValidationResult::Valid => Ok(None),
+            ValidationResult::Invalid => Self::parse_domain_literal(domain_bytes, policy).map(Some),
+            ValidationResult::RequiresIdnCheck => {
+                Self::validate_idn_domain(domain_bytes, dispatch).map(|()| None)
             }
         }
-        None
     }
 
     #[inline]
-    pub fn validate(email_bytes: &[u8]) -> bool {
+    pub fn parse_with_policy(
+        email_bytes: &[u8],
+        policy: ValidationPolicy,
+    ) -> Result<EmailAddress<'_>, EmailValidationError> {
+        Self::parse_with(email_bytes, policy, Dispatch::detect())
+    }
+
+    /// The body of [`Self::parse_with_policy`], scanning with an instruction
+    /// set the caller already detected.
+    #[inline]
+    fn parse_with(
+        email_bytes: &[u8],
+        policy: ValidationPolicy,
+        dispatch: Dispatch,
+    ) -> Result<EmailAddress<'_>, EmailValidationError> {
         let len = email_bytes.len();
-        if len < MIN_EMAIL_LENGTH || len > MAX_EMAIL_LENGTH {
-            return false;
+        if !(MIN_EMAIL_LENGTH..=MAX_EMAIL_LENGTH).contains(&len) {
+            return Err(Self::length_error(len));
         }
 
-        let at_pos = match Self::find_last_at_position(email_bytes) {
-            Some(pos) => pos,
-            None => return false,
+        let Some(at_pos) = Self::find_last_at_position(email_bytes, dispatch) else {
+            return Err(Self::at_symbol_error(email_bytes));
         };
 
-        unsafe {
-            let user_bytes = email_bytes.get_unchecked(..at_pos);
-            let domain_bytes = email_bytes.get_unchecked(at_pos + 1..);
+        let (user_bytes, domain_bytes) = unsafe {
+            (
+                email_bytes.get_unchecked(..at_pos),
+                email_bytes.get_unchecked(at_pos + 1..),
+            )
+        };
 
-            if !Self::validate_user_part(user_bytes) {
-                return false;
-            }
+        if !Self::validate_user_part(user_bytes, dispatch) {
+            return Err(Self::user_part_error(user_bytes));
+        }
 
-            match Self::validate_domain_part(domain_bytes) {
-                ValidationResult::Valid => true,
-                ValidationResult::Invalid => Self::validate_ip_literal(domain_bytes),
-                ValidationResult::RequiresIdnCheck => {
-                    let domain_str = std::str::from_utf8_unchecked(domain_bytes);
-                    match domain_to_ascii(domain_str) {
-                        Ok(ascii_domain) => {
-                            Self::validate_domain_part(ascii_domain.as_bytes()).is_valid()
-                        }
-                        Err(_) => false,
-                    }
-                }
-            }
+        let ip_literal = Self::parse_domain_with(domain_bytes, policy, dispatch)?;
+
+        // SAFETY: the user part and any IP literal are ASCII-only, and a
+        // non-ASCII domain only gets here after passing UTF-8 validation.
+        let address = unsafe { core::str::from_utf8_unchecked(email_bytes) };
+        Ok(EmailAddress::new(address, at_pos, ip_literal))
+    }
+
+    #[inline]
+    pub fn parse(email_bytes: &[u8]) -> Result<EmailAddress<'_>, EmailValidationError> {
+        Self::parse_with_policy(email_bytes, ValidationPolicy::DEFAULT)
+    }
+
+    #[inline]
+    pub fn parse_str(email: &str) -> Result<EmailAddress<'_>, EmailValidationError> {
+        Self::parse(email.as_bytes())
+    }
+
+    /// Validates an address with the default policy in a const context, as
+    /// used by the [`email!`](crate::email!) macro.
+    ///
+    /// This is the scalar ASCII path of [`Self::parse_str`]: IP literals are
+    /// rejected with `InvalidDomainPart` and internationalized domains with
+    /// `IdnProcessingFailed`, since neither can be checked at compile time.
+    pub const fn parse_const(email: &str) -> Result<EmailAddress<'_>, EmailValidationError> {
+        let bytes = email.as_bytes();
+        let len = bytes.len();
+        if len < MIN_EMAIL_LENGTH || len > MAX_EMAIL_LENGTH {
+            return Err(Self::length_error(len));
+        }
+
+        // The `@` can be neither the first nor the last byte.
+        let at_pos = match rposition(bytes.split_at(len - 1).0, b'@') {
+            Some(pos) if pos > 0 => pos,
+            _ => return Err(Self::at_symbol_error(bytes)),
+        };
+        let (user_bytes, rest) = bytes.split_at(at_pos);
+        let domain_bytes = rest.split_at(1).1;
+
+        if user_bytes.len() > MAX_USER_LENGTH || !Self::validate_user_part_slow_path(user_bytes) {
+            return Err(Self::user_part_error(user_bytes));
+        }
+
+        if !Self::is_domain_length_valid(domain_bytes) {
+            return Err(EmailValidationError::InvalidDomainPart);
+        }
+        match Self::validate_domain_labels(domain_bytes) {
+            ValidationResult::Valid => Ok(EmailAddress::new(email, at_pos, None)),
+            ValidationResult::Invalid => Err(EmailValidationError::InvalidDomainPart),
+            ValidationResult::RequiresIdnCheck => Err(EmailValidationError::IdnProcessingFailed),
         }
     }
 
     #[inline]
+    #[must_use]
+    pub const fn validate_const(email: &str) -> bool {
+        Self::parse_const(email).is_ok()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn validate_with_policy(email_bytes: &[u8], policy: ValidationPolicy) -> bool {
+        Self::parse_with_policy(email_bytes, policy).is_ok()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn validate(email_bytes: &[u8]) -> bool {
+        Self::validate_with_policy(email_bytes, ValidationPolicy::DEFAULT)
+    }
+
+    #[inline]
+    #[must_use]
     pub fn validate_str(email: &str) -> bool {
         Self::validate(email.as_bytes())
     }
 
     #[inline]
+    #[must_use]
+    #[allow(clippy::ptr_arg)]
+    #[cfg(feature = "alloc")]
     pub fn validate_string(email: &String) -> bool {
         Self::validate(email.as_bytes())
     }
+
+    /// Validates every address in `emails`, returning one flag per input.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn validate_batch<S: AsRef<str>>(emails: &[S]) -> Vec<bool> {
+        let mut results = vec![false; emails.len()];
+        Self::validate_batch_into(emails, &mut results);
+        results
+    }
+
+    /// Like [`Self::validate_batch`] but writes into a caller-provided buffer
+    /// so it can be reused across batches. Returns the number of valid
+    /// addresses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `results` is shorter than `emails`.
+    #[inline]
+    pub fn validate_batch_into<S: AsRef<str>>(emails: &[S], results: &mut [bool]) -> usize {
+        Self::validate_batch_into_with_policy(emails, ValidationPolicy::DEFAULT, results)
+    }
+
+    /// Like [`Self::validate_batch_into`] with a custom policy.
+    ///
+    /// With AVX2, addresses are classified four at a time, and a plain ASCII
+    /// address of up to 32 bytes, the common case, is accepted from its byte
+    /// classes alone. Anything else goes through
+    /// [`Self::validate_with_policy`] as usual.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `results` is shorter than `emails`.
+    pub fn validate_batch_into_with_policy<S: AsRef<str>>(
+        emails: &[S],
+        policy: ValidationPolicy,
+        results: &mut [bool],
+    ) -> usize {
+        assert!(
+            results.len() >= emails.len(),
+            "result buffer holds {} flags but {} addresses were given",
+            results.len(),
+            emails.len()
+        );
+
+        let dispatch = Dispatch::detect();
+        let mut valid = 0;
+        for (chunk, flags) in emails
+            .chunks(BATCH_LANES)
+            .zip(results[..emails.len()].chunks_mut(BATCH_LANES))
+        {
+            let mut lanes: [&[u8]; BATCH_LANES] = [&[]; BATCH_LANES];
+            for (lane, email) in lanes.iter_mut().zip(chunk) {
+                *lane = email.as_ref().as_bytes();
+            }
+
+            let classes = dispatch.classify_short(lanes);
+            for (i, (bytes, flag)) in lanes.iter().zip(flags).enumerate() {
+                *flag = classes
+                    .is_some_and(|classes| Self::accepts_classified(bytes.len(), &classes[i]))
+                    || Self::parse_with(bytes, policy, dispatch).is_ok();
+                valid += usize::from(*flag);
+            }
+        }
+        valid
+    }
+
+    /// Whether the byte classes of an address of `len` bytes prove it valid:
+    /// a dot-atom local part and a domain of well-formed ASCII labels.
+    /// `false` only means the full parser has to decide.
+    #[inline]
+    const fn accepts_classified(len: usize, classes: &ByteClasses) -> bool {
+        if len < MIN_EMAIL_LENGTH || len > SIMD_CHUNK_SIZE || classes.at == 0 {
+            return false;
+        }
+
+        let at_pos = classes.at.ilog2() as usize;
+        if at_pos == 0 || at_pos + 1 >= len {
+            return false;
+        }
+
+        let user = low_bits(at_pos);
+        let user_dots = classes.dot & user;
+        if classes.user & user != user
+            || user_dots & (1 | 1 << (at_pos - 1)) != 0
+            || user_dots & (user_dots >> 1) != 0
+        {
+            return false;
+        }
+
+        // `at_pos + 1 < len <= 32`, so the shift stays in range.
+        let domain_len = len - at_pos - 1;
+        let domain = low_bits(domain_len);
+        let dots = (classes.dot >> (at_pos + 1)) & domain;
+        let hyphens = (classes.hyphen >> (at_pos + 1)) & domain;
+        let (first, last) = (1, 1 << (domain_len - 1));
+        let label_edges = first | last | (dots << 1) | (dots >> 1);
+
+        (classes.domain >> (at_pos + 1)) & domain == domain
+            && dots & (first | last) == 0
+            && dots & (dots >> 1) == 0
+            && hyphens & label_edges == 0
+    }
+}
+
+/// A mask of the lowest `n` bits, for `n` up to 32.
+const fn low_bits(n: usize) -> u32 {
+    if n >= u32::BITS as usize {
+        u32::MAX
+    } else {
+        (1 << n) - 1
+    }
+}
+
+const fn position(bytes: &[u8], needle: u8) -> Option<usize> {
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == needle {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn rposition(bytes: &[u8], needle: u8) -> Option<usize> {
+    let mut i = bytes.len();
+    while i > 0 {
+        i -= 1;
+        if bytes[i] == needle {
+            return Some(i);
+        }
+    }
+    None
 }
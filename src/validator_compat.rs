@@ -0,0 +1,29 @@
+//! Drop-in custom validation for the `validator` crate, enabled with the
+//! `validator` feature.
+//!
+//! Annotate string fields with
+//! `#[validate(custom(function = "email_syntax_verify_opt::validator_compat::validate_email"))]`
+//! in place of `#[validate(email)]`; `Option<String>` fields work too.
+
+use crate::error::EmailValidationError;
+use crate::validator::EmailValidator;
+use ::validator::ValidationError;
+use std::borrow::Cow;
+
+impl From<EmailValidationError> for ValidationError {
+    /// Uses [`EmailValidationError::code`] as the code and the human-readable
+    /// description as the message.
+    #[inline]
+    fn from(err: EmailValidationError) -> Self {
+        Self::new(err.code()).with_message(Cow::Borrowed(err.as_str()))
+    }
+}
+
+/// Validates an address with the signature `#[validate(custom(...))]`
+/// expects, so it can replace `#[validate(email)]` on any string field.
+#[inline]
+pub fn validate_email<T: AsRef<str> + ?Sized>(value: &T) -> Result<(), ValidationError> {
+    EmailValidator::parse_str(value.as_ref())
+        .map(|_| ())
+        .map_err(ValidationError::from)
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "actix-web")]
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use email_syntax_verify_opt::actix::{EmailPath, EmailQuery};
+use email_syntax_verify_opt::{
+    Email, EmailFieldError, EmailFieldErrors, ValidateEmail, ValidateEmails, ValidationPolicy,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Invite {
+    email: String,
+    cc: Option<String>,
+}
+
+impl ValidateEmails for Invite {
+    fn validate_emails(&self) -> Result<(), EmailFieldErrors> {
+        let mut errors: Vec<EmailFieldError> = Vec::new();
+        let policy = ValidationPolicy::DEFAULT;
+        if let Err(error) = self.email.check_email(policy) {
+            errors.push(EmailFieldError::new("email", error));
+        }
+        if let Err(error) = self.cc.check_email(policy) {
+            errors.push(EmailFieldError::new("cc", error));
+        }
+        EmailFieldErrors::check(errors)
+    }
+}
+
+#[derive(Deserialize)]
+struct Subscribe {
+    email: Email,
+    #[allow(dead_code)]
+    list: String,
+}
+
+impl ValidateEmails for Subscribe {
+    fn validate_emails(&self) -> Result<(), EmailFieldErrors> {
+        Ok(())
+    }
+}
+
+async fn invite(EmailQuery(invite): EmailQuery<Invite>) -> String {
+    invite.email
+}
+
+async fn subscribe(EmailQuery(subscribe): EmailQuery<Subscribe>) -> String {
+    subscribe.email.into_string()
+}
+
+async fn invite_path(EmailPath(invite): EmailPath<Invite>) -> String {
+    invite.email
+}
+
+async fn user(EmailPath(email): EmailPath<Email>) -> String {
+    email.into_string()
+}
+
+async fn alias(EmailPath(email): EmailPath<String>) -> String {
+    email
+}
+
+async fn get_uri(uri: &str) -> (StatusCode, String) {
+    let app = test::init_service(
+        App::new()
+            .route("/invite", web::get().to(invite))
+            .route("/subscribe", web::get().to(subscribe))
+            .route("/invites/{email}", web::get().to(invite_path))
+            .route("/users/{email}", web::get().to(user))
+            .route("/aliases/{email}", web::get().to(alias)),
+    )
+    .await;
+    let response = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+    let body = test::read_body(response).await;
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[cfg(test)]
+mod actix_tests {
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_valid_query_is_extracted() {
+        let (status, body) = get_uri("/invite?email=user%40example.com").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "user@example.com");
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_query_is_unprocessable() {
+        let (status, body) = get_uri("/invite?email=user.example.com&cc=a%40%40b.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            "{\"errors\":[\
+             {\"field\":\"email\",\"code\":\"no_at_symbol\",\"message\":\"Email missing @ symbol\"},\
+             {\"field\":\"cc\",\"code\":\"multiple_at_symbols\",\
+             \"message\":\"Email has multiple @ symbols\"}]}"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_malformed_query_keeps_actix_rejection() {
+        let (status, _) = get_uri("/invite?cc=a%40example.com").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_path_parameters() {
+        let (status, body) = get_uri("/invites/user@example.com").await;
+        assert_eq!(
+            (status, body.as_str()),
+            (StatusCode::OK, "user@example.com")
+        );
+
+        let (status, body) = get_uri("/invites/@example.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains("\"code\":\"invalid_user_part\""));
+    }
+
+    #[actix_web::test]
+    async fn test_single_address_path() {
+        let (status, body) = get_uri("/users/user@example.com").await;
+        assert_eq!(
+            (status, body.as_str()),
+            (StatusCode::OK, "user@example.com")
+        );
+
+        let (status, body) = get_uri("/users/@example.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            "{\"errors\":[{\"field\":\"email\",\"code\":\"invalid_user_part\",\
+             \"message\":\"Invalid user part\"}]}"
+        );
+
+        let (status, body) = get_uri("/aliases/user.example.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains("\"field\":\"\",\"code\":\"no_at_symbol\""));
+    }
+
+    #[actix_web::test]
+    async fn test_email_fields_in_query() {
+        let (status, body) = get_uri("/subscribe?list=news&email=user%40example.com").await;
+        assert_eq!(
+            (status, body.as_str()),
+            (StatusCode::OK, "user@example.com")
+        );
+
+        let (status, body) = get_uri("/subscribe?list=news&email=user.example.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains("{\"field\":\"email\",\"code\":\"no_at_symbol\""));
+
+        // Failures unrelated to addresses keep the framework's rejection.
+        let (status, _) = get_uri("/subscribe?email=user%40example.com").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "alloc")]
+
+use email_syntax_verify_opt::{
+    parse_address_list, AddressList, EmailValidationError, MailboxError, ValidationPolicy,
+};
+
+fn collect(input: &str) -> Vec<(Option<String>, Option<String>, String)> {
+    parse_address_list(input)
+        .map(|entry| {
+            let entry = entry.unwrap_or_else(|err| panic!("`{input}` failed: {err}"));
+            (
+                entry.group().map(str::to_owned),
+                entry.mailbox().display_name().map(str::to_owned),
+                entry.mailbox().address().as_str().to_owned(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod address_list_tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_address_list() {
+        let entries = collect("Alice <a@x.com>, \"Bob, Jr.\" <b@y.org>, team: c@z.net, d@w.io;");
+
+        assert_eq!(
+            entries,
+            vec![
+                (None, Some("Alice".into()), "a@x.com".into()),
+                (None, Some("Bob, Jr.".into()), "b@y.org".into()),
+                (Some("team".into()), None, "c@z.net".into()),
+                (Some("team".into()), None, "d@w.io".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bare_addresses() {
+        let entries = collect("a@x.com,b@y.org , c@z.net");
+        let addresses: Vec<_> = entries.into_iter().map(|(_, _, addr)| addr).collect();
+        assert_eq!(addresses, ["a@x.com", "b@y.org", "c@z.net"]);
+    }
+
+    #[test]
+    fn test_comments_and_folding() {
+        let entries = collect(
+            "John (the man)\r\n Doe <john@example.com> (work),\r\n\t(nobody) jane@example.com (Jane)",
+        );
+
+        assert_eq!(
+            entries,
+            vec![
+                (None, Some("John Doe".into()), "john@example.com".into()),
+                (None, None, "jane@example.com".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_specials_inside_quotes_and_comments() {
+        let entries = collect("\"a <fake@x.com>, b;\" <real@x.com>, Name (c: d, e;) <other@y.org>");
+
+        assert_eq!(
+            entries,
+            vec![
+                (None, Some("a <fake@x.com>, b;".into()), "real@x.com".into()),
+                (None, Some("Name".into()), "other@y.org".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_escaped_quoted_display_name() {
+        let entries = collect(r#""Dwayne \"The Rock\" Johnson" <rock@example.com>"#);
+        assert_eq!(
+            entries[0].1.as_deref(),
+            Some(r#"Dwayne "The Rock" Johnson"#)
+        );
+    }
+
+    #[test]
+    fn test_empty_group_and_elements() {
+        assert!(collect("undisclosed-recipients:;").is_empty());
+        assert!(collect("").is_empty());
+        assert!(collect(" , ,").is_empty());
+
+        let entries = collect("a@x.com, , Friends: ;, b@y.org");
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn test_ip_literal_in_group() {
+        let entries = collect("ops: root@[IPv6:2001:db8::1], admin@[10.0.0.1];");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].2, "root@[IPv6:2001:db8::1]");
+    }
+
+    #[test]
+    fn test_policy_is_applied() {
+        let input = "Router <admin@[IPv6:fe80::1%eth0]>";
+
+        let default: Vec<_> = AddressList::new(input).collect();
+        assert_eq!(
+            default,
+            vec![Err(MailboxError::InvalidAddress(
+                EmailValidationError::IpZoneIdNotAllowed
+            ))]
+        );
+
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        assert!(AddressList::with_policy(input, policy).all(|entry| entry.is_ok()));
+    }
+}
+
+#[cfg(test)]
+mod address_list_error_tests {
+    use super::*;
+
+    fn first_error(input: &str) -> MailboxError {
+        parse_address_list(input)
+            .find_map(Result::err)
+            .unwrap_or_else(|| panic!("`{input}` should fail"))
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let cases = [
+            (
+                "\"Unterminated <a@x.com>",
+                MailboxError::UnterminatedQuotedString,
+            ),
+            (
+                "Name (unterminated <a@x.com>",
+                MailboxError::UnterminatedComment,
+            ),
+            ("Name <a@x.com", MailboxError::UnterminatedAngleAddr),
+            ("team: a@x.com, b@y.org", MailboxError::UnterminatedGroup),
+            ("outer: inner: a@x.com;;", MailboxError::NestedGroup),
+            ("a@x.com b@y.org", MailboxError::UnexpectedCharacter),
+            ("<a@x.com> trailing", MailboxError::UnexpectedCharacter),
+            ("a@x.com;", MailboxError::UnexpectedCharacter),
+            ("Bad @ Name <a@x.com>", MailboxError::InvalidDisplayName),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(first_error(input), expected, "`{input}`");
+        }
+    }
+
+    #[test]
+    fn test_invalid_addresses() {
+        assert_eq!(
+            first_error("Alice <a@x.com>, Bob <not-an-address>"),
+            MailboxError::InvalidAddress(EmailValidationError::NoAtSymbol)
+        );
+        assert_eq!(
+            first_error("Empty <>"),
+            MailboxError::InvalidAddress(EmailValidationError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_iteration_stops_after_error() {
+        let results: Vec<_> = parse_address_list("a@x.com, bad, c@z.net").collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use email_syntax_verify_opt::idn::to_ascii;
+use email_syntax_verify_opt::{validate_email, EmailValidator};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: Every call is forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Heap allocations made by `f` on the current thread.
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[cfg(test)]
+mod allocation_tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_validation_does_not_allocate() {
+        let long_local = format!("{}@example.com", "a".repeat(64));
+        let cases = [
+            ("user@example.com", true),
+            ("first.last+tag@sub.example.co.uk", true),
+            ("admin@[192.168.1.1]", true),
+            ("admin@[IPv6:2001:db8::1]", true),
+            (long_local.as_str(), true),
+            ("user.example.com", false),
+            ("user@@example.com", false),
+            ("user@-example.com", false),
+        ];
+
+        for (email, expected) in cases {
+            let (valid, count) = allocations(|| validate_email(email));
+            assert_eq!(valid, expected, "{email}");
+            assert_eq!(count, 0, "{email}");
+        }
+    }
+
+    #[test]
+    fn test_idn_validation_does_not_allocate() {
+        for email in [
+            "user@münchen.de",
+            "user@mañana.example.com",
+            "USER@BÜCHER.DE",
+        ] {
+            let (valid, count) = allocations(|| validate_email(email));
+            assert!(valid, "{email}");
+            assert_eq!(count, 0, "{email}");
+
+            let (result, count) = allocations(|| EmailValidator::parse_str(email).is_ok());
+            assert!(result, "{email}");
+            assert_eq!(count, 0, "{email}");
+        }
+
+        // Domains outside the stack subset fall back to `idna`, which allocates.
+        assert!(!validate_email("user@bü\u{E000}cher.de"));
+    }
+
+    #[test]
+    fn test_to_ascii_does_not_allocate() {
+        let (domain, count) = allocations(|| to_ascii("bücher.example.com"));
+        assert_eq!(count, 0);
+        assert_eq!(domain.unwrap().as_str(), "xn--bcher-kva.example.com");
+    }
+
+    #[test]
+    fn test_counter_sees_allocations() {
+        let (_, count) = allocations(|| String::from("user@example.com"));
+        assert_eq!(count, 1);
+    }
+}
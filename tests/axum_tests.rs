@@ -0,0 +1,167 @@
+#![cfg(feature = "axum")]
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use email_syntax_verify_opt::axum::{EmailPath, EmailQuery};
+use email_syntax_verify_opt::{
+    Email, EmailFieldError, EmailFieldErrors, ValidateEmail, ValidateEmails, ValidationPolicy,
+};
+use serde::Deserialize;
+use tower::ServiceExt;
+
+#[derive(Deserialize)]
+struct Invite {
+    email: String,
+    cc: Option<String>,
+}
+
+impl ValidateEmails for Invite {
+    fn validate_emails(&self) -> Result<(), EmailFieldErrors> {
+        let mut errors: Vec<EmailFieldError> = Vec::new();
+        let policy = ValidationPolicy::DEFAULT;
+        if let Err(error) = self.email.check_email(policy) {
+            errors.push(EmailFieldError::new("email", error));
+        }
+        if let Err(error) = self.cc.check_email(policy) {
+            errors.push(EmailFieldError::new("cc", error));
+        }
+        EmailFieldErrors::check(errors)
+    }
+}
+
+#[derive(Deserialize)]
+struct Subscribe {
+    email: Email,
+    #[allow(dead_code)]
+    list: String,
+}
+
+impl ValidateEmails for Subscribe {
+    fn validate_emails(&self) -> Result<(), EmailFieldErrors> {
+        Ok(())
+    }
+}
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/invite",
+            get(|EmailQuery(invite): EmailQuery<Invite>| async move { invite.email }),
+        )
+        .route(
+            "/subscribe",
+            get(|EmailQuery(subscribe): EmailQuery<Subscribe>| async move {
+                subscribe.email.into_string()
+            }),
+        )
+        .route(
+            "/invites/{email}",
+            get(|EmailPath(invite): EmailPath<Invite>| async move { invite.email }),
+        )
+        .route(
+            "/users/{email}",
+            get(|EmailPath(email): EmailPath<Email>| async move { email.into_string() }),
+        )
+        .route(
+            "/aliases/{email}",
+            get(|EmailPath(email): EmailPath<String>| async move { email }),
+        )
+}
+
+async fn get_uri(uri: &str) -> (StatusCode, String) {
+    let response = app()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[cfg(test)]
+mod axum_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_valid_query_is_extracted() {
+        let (status, body) = get_uri("/invite?email=user%40example.com").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "user@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_query_is_unprocessable() {
+        let (status, body) = get_uri("/invite?email=user.example.com&cc=a%40%40b.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            "{\"errors\":[\
+             {\"field\":\"email\",\"code\":\"no_at_symbol\",\"message\":\"Email missing @ symbol\"},\
+             {\"field\":\"cc\",\"code\":\"multiple_at_symbols\",\
+             \"message\":\"Email has multiple @ symbols\"}]}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_malformed_query_keeps_axum_rejection() {
+        let (status, _) = get_uri("/invite?cc=a%40example.com").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_path_parameters() {
+        let (status, body) = get_uri("/invites/user@example.com").await;
+        assert_eq!(
+            (status, body.as_str()),
+            (StatusCode::OK, "user@example.com")
+        );
+
+        let (status, body) = get_uri("/invites/@example.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains("\"code\":\"invalid_user_part\""));
+    }
+
+    #[tokio::test]
+    async fn test_single_address_path() {
+        let (status, body) = get_uri("/users/user@example.com").await;
+        assert_eq!(
+            (status, body.as_str()),
+            (StatusCode::OK, "user@example.com")
+        );
+
+        let (status, body) = get_uri("/users/@example.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            "{\"errors\":[{\"field\":\"email\",\"code\":\"invalid_user_part\",\
+             \"message\":\"Invalid user part\"}]}"
+        );
+
+        let (status, body) = get_uri("/aliases/user.example.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            "{\"errors\":[{\"field\":\"\",\"code\":\"no_at_symbol\",\
+             \"message\":\"Email missing @ symbol\"}]}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_email_fields_in_query() {
+        let (status, body) = get_uri("/subscribe?list=news&email=user%40example.com").await;
+        assert_eq!(
+            (status, body.as_str()),
+            (StatusCode::OK, "user@example.com")
+        );
+
+        let (status, body) = get_uri("/subscribe?list=news&email=user.example.com").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains("{\"field\":\"email\",\"code\":\"no_at_symbol\""));
+
+        // Failures unrelated to addresses keep the framework's rejection.
+        let (status, _) = get_uri("/subscribe?email=user%40example.com").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "clap")]
+
+use clap::error::ErrorKind;
+use clap::Parser;
+use email_syntax_verify_opt::{Email, EmailValueParser, ValidationPolicy};
+
+const ZONES_ALLOWED: EmailValueParser =
+    EmailValueParser::with_policy(ValidationPolicy::new().allow_ip_zone_id(true));
+
+#[derive(Debug, Parser)]
+#[command(name = "notify")]
+struct Cli {
+    #[arg(long)]
+    notify: Vec<Email>,
+    #[arg(long, value_parser = ZONES_ALLOWED)]
+    relay: Option<Email>,
+}
+
+#[cfg(test)]
+mod clap_tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_arguments() {
+        let cli = Cli::try_parse_from([
+            "notify",
+            "--notify",
+            "a@example.com",
+            "--notify=b@example.org",
+            "--relay",
+            "admin@[IPv6:fe80::1%eth0]",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.notify, ["a@example.com", "b@example.org"]);
+        assert_eq!(cli.relay.unwrap().domain(), "[IPv6:fe80::1%eth0]");
+    }
+
+    #[test]
+    fn test_invalid_argument_reports_reason() {
+        let err = Cli::try_parse_from(["notify", "--notify", "user.example.com"]).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        assert!(err.to_string().starts_with(
+            "error: invalid email address 'user.example.com' for '--notify <NOTIFY>': \
+             Email missing @ symbol [no_at_symbol]\n"
+        ));
+    }
+
+    #[test]
+    fn test_policy_selection() {
+        let err =
+            Cli::try_parse_from(["notify", "--notify", "admin@[IPv6:fe80::1%eth0]"]).unwrap_err();
+        assert!(err.to_string().contains("[ip_zone_id_not_allowed]"));
+    }
+}
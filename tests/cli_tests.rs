@@ -0,0 +1,202 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn cli(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_email_syntax_verify_opt"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut input = child.stdin.take().unwrap();
+    input.write_all(stdin.unwrap_or("").as_bytes()).unwrap();
+    drop(input);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+/// A fresh directory under cargo's scratch space for one test's files.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_status_reflects_validity() {
+        let output = cli(&["a@example.com", "b@example.org"], None);
+        assert_eq!(output.status.code(), Some(0));
+        assert_eq!(
+            stdout(&output),
+            "valid\ta@example.com\nvalid\tb@example.org\n"
+        );
+
+        let output = cli(&["a@example.com", "broken"], None);
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(stdout(&output), "valid\ta@example.com\ninvalid\tbroken\n");
+    }
+
+    #[test]
+    fn test_reads_stdin_without_arguments() {
+        let output = cli(&["--reason"], Some("a@example.com\r\nx@@example.com\n"));
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(
+            stdout(&output),
+            "valid\ta@example.com\ninvalid\tx@@example.com\tmultiple_at_symbols\n"
+        );
+    }
+
+    #[test]
+    fn test_json_output() {
+        let output = cli(&["-j", "-r", "-c", "A@Example.COM", "\"quoted\""], None);
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(
+            stdout(&output),
+            "{\"input\":\"A@Example.COM\",\"valid\":true,\"canonical\":\"A@example.com\"}\n\
+             {\"input\":\"\\\"quoted\\\"\",\"valid\":false,\"reason\":\"no_at_symbol\",\
+             \"message\":\"Email missing @ symbol\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_and_policy_flags() {
+        let zoned = "admin@[IPv6:FE80:0::1%eth0]";
+        assert_eq!(cli(&[zoned], None).status.code(), Some(1));
+
+        let output = cli(&["--allow-ip-zone-id", "--canonicalize", zoned], None);
+        assert_eq!(output.status.code(), Some(0));
+        assert_eq!(stdout(&output), "valid\tadmin@[IPv6:fe80::1%eth0]\n");
+    }
+
+    #[test]
+    fn test_quiet_and_separator() {
+        let output = cli(&["-q", "--", "-dash@example.com"], None);
+        assert_eq!(output.status.code(), Some(0));
+        assert!(output.stdout.is_empty());
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        let output = cli(&["--no-such-flag"], None);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("--no-such-flag"));
+
+        let output = cli(&["--help"], None);
+        assert_eq!(output.status.code(), Some(0));
+        assert!(stdout(&output).starts_with("Usage:"));
+    }
+}
+
+#[cfg(test)]
+mod clean_tests {
+    use super::*;
+
+    const LIST: &str = "\
+\u{FEFF}Ops@Example.COM
+bad
+ops@example.com
+x@gmial.con
+Ops@example.com\r
+y@@gmial.con
+
+u@münchen.de
+z@gmial.con
+v@[1.2.3.4]
+w@GMIAL.con
+";
+
+    #[test]
+    fn test_clean_writes_split_files_and_summary() {
+        let dir = scratch_dir("clean_split");
+        let input = dir.join("contacts.txt");
+        fs::write(&input, LIST).unwrap();
+
+        let output = cli(&["clean", "--top", "1", input.to_str().unwrap()], None);
+        assert_eq!(output.status.code(), Some(0));
+
+        let read = |name: &str| fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!(
+            read("contacts.valid.txt"),
+            "Ops@example.com\nops@example.com\nx@gmial.con\nu@xn--mnchen-3ya.de\n\
+             z@gmial.con\nv@[1.2.3.4]\nw@gmial.con\n"
+        );
+        assert_eq!(
+            read("contacts.invalid.txt"),
+            "2\tbad\tno_at_symbol\n6\ty@@gmial.con\tmultiple_at_symbols\n"
+        );
+        assert_eq!(read("contacts.duplicates.txt"), "5\tOps@example.com\t1\n");
+
+        assert_eq!(
+            stdout(&output),
+            "\
+Lines read:        10
+Valid addresses:   7
+  IDN domains:     1
+  IP literals:     1
+Duplicates:        1
+Invalid addresses: 2
+
+Invalid by reason:
+  multiple_at_symbols      1
+  no_at_symbol             1
+
+Top invalid domains:
+  gmial.con                1
+"
+        );
+    }
+
+    #[test]
+    fn test_clean_explicit_outputs_from_stdin() {
+        let dir = scratch_dir("clean_stdin");
+        let path = |name: &str| dir.join(name).to_str().unwrap().to_owned();
+        let (valid, invalid, duplicates) = (path("v"), path("i"), path("d"));
+
+        let output = cli(
+            &[
+                "clean",
+                "--valid",
+                &valid,
+                "--invalid",
+                &invalid,
+                "--duplicates",
+                &duplicates,
+                "-",
+            ],
+            Some("a@example.com\na@EXAMPLE.COM\n"),
+        );
+        assert_eq!(output.status.code(), Some(0));
+        assert_eq!(fs::read_to_string(valid).unwrap(), "a@example.com\n");
+        assert_eq!(fs::read_to_string(invalid).unwrap(), "");
+        assert_eq!(
+            fs::read_to_string(duplicates).unwrap(),
+            "2\ta@EXAMPLE.COM\t1\n"
+        );
+    }
+
+    #[test]
+    fn test_clean_usage_errors() {
+        assert_eq!(cli(&["clean"], None).status.code(), Some(2));
+        assert_eq!(
+            cli(&["clean", "--top", "many", "x"], None).status.code(),
+            Some(2)
+        );
+
+        let missing = scratch_dir("clean_missing").join("missing.txt");
+        let output = cli(&["clean", missing.to_str().unwrap()], None);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("missing.txt"));
+    }
+}
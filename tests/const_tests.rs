@@ -0,0 +1,87 @@
+use email_syntax_verify_opt::{email, EmailAddress, EmailValidationError, EmailValidator};
+
+const SENDER: EmailAddress<'static> = email!("noreply@ourcorp.com");
+static REPLY_TO: EmailAddress<'static> = email!("support+tickets@help.ourcorp.com");
+
+const MISSING_AT: Result<EmailAddress<'static>, EmailValidationError> =
+    EmailValidator::parse_const("noreply.ourcorp.com");
+const _: () = assert!(EmailValidator::validate_const("first.last@example.co.uk"));
+
+#[cfg(test)]
+mod const_validation_tests {
+    use super::*;
+
+    #[test]
+    fn test_email_macro() {
+        assert_eq!(SENDER.as_str(), "noreply@ourcorp.com");
+        assert_eq!(SENDER.local_part(), "noreply");
+        assert_eq!(SENDER.domain(), "ourcorp.com");
+        assert_eq!(REPLY_TO.domain(), "help.ourcorp.com");
+
+        let inline = email!("a@b.co");
+        assert_eq!(inline, EmailValidator::parse_str("a@b.co").unwrap());
+    }
+
+    #[test]
+    fn test_const_evaluation() {
+        assert_eq!(MISSING_AT, Err(EmailValidationError::NoAtSymbol));
+    }
+
+    #[test]
+    fn test_matches_runtime_validation() {
+        let long_local = format!("{}@example.com", "a".repeat(65));
+        let long_label = format!("user@{}.com", "a".repeat(64));
+        let long_domain = format!("user@{}.com", ["a"; 130].join("."));
+        let long_email = format!("{}@{}.com", "a".repeat(64), "b".repeat(300));
+        let cases = [
+            "user@example.com",
+            "first.last+tag@sub.example.co.uk",
+            "a!#$%&'*+/=?^_`{|}~-@example.org",
+            "x@y.z",
+            "a@b",
+            "",
+            "a@",
+            "@example.com",
+            "user@",
+            "user.example.com",
+            "user@@example.com",
+            "a@b@example.com",
+            ".user@example.com",
+            "user.@example.com",
+            "us..er@example.com",
+            "us er@example.com",
+            "tëst@example.com",
+            "user@.example.com",
+            "user@example.com.",
+            "user@example..com",
+            "user@-example.com",
+            "user@example-.com",
+            "user@exa_mple.com",
+            long_local.as_str(),
+            long_label.as_str(),
+            long_domain.as_str(),
+            long_email.as_str(),
+        ];
+
+        for email in cases {
+            assert_eq!(
+                EmailValidator::parse_const(email),
+                EmailValidator::parse_str(email),
+                "{email}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_runtime_only_domains() {
+        assert_eq!(
+            EmailValidator::parse_const("user@münchen.de"),
+            Err(EmailValidationError::IdnProcessingFailed)
+        );
+        assert_eq!(
+            EmailValidator::parse_const("admin@[192.168.1.1]"),
+            Err(EmailValidationError::InvalidDomainPart)
+        );
+        assert!(EmailValidator::parse_str("admin@[192.168.1.1]").is_ok());
+    }
+}
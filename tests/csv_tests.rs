@@ -0,0 +1,105 @@
+#![cfg(feature = "csv")]
+
+use email_syntax_verify_opt::{CsvError, CsvValidator, ValidationPolicy};
+
+fn run(validator: &CsvValidator, input: &str) -> (String, String, (usize, usize)) {
+    let mut valid = Vec::new();
+    let mut invalid = Vec::new();
+    let report = validator
+        .run(input.as_bytes(), &mut valid, &mut invalid)
+        .unwrap();
+
+    assert_eq!(report.rows(), report.valid() + report.invalid());
+    (
+        String::from_utf8(valid).unwrap(),
+        String::from_utf8(invalid).unwrap(),
+        (report.valid(), report.invalid()),
+    )
+}
+
+#[cfg(test)]
+mod csv_column_tests {
+    use super::*;
+
+    const CONTACTS: &str = "\
+id,name,Email
+1,Alice,alice@example.com
+2,Bob,bob.example.com
+3,\"Carol, Jr.\", carol@example.org
+4,Dave,dave@@example.com
+5,Eve
+";
+
+    #[test]
+    fn test_select_column_by_name() {
+        let (valid, invalid, counts) = run(&CsvValidator::new("email"), CONTACTS);
+
+        assert_eq!(counts, (2, 3));
+        assert_eq!(
+            valid,
+            "id,name,Email\n1,Alice,alice@example.com\n3,\"Carol, Jr.\", carol@example.org\n"
+        );
+        assert_eq!(
+            invalid,
+            "id,name,Email,reason\n\
+             2,Bob,bob.example.com,no_at_symbol\n\
+             4,Dave,dave@@example.com,multiple_at_symbols\n\
+             5,Eve,empty\n"
+        );
+    }
+
+    #[test]
+    fn test_select_column_by_index() {
+        let by_name = run(&CsvValidator::new("Email"), CONTACTS);
+        let by_index = run(&CsvValidator::new(2), CONTACTS);
+        assert_eq!(by_name, by_index);
+    }
+
+    #[test]
+    fn test_without_headers_and_custom_delimiter() {
+        let validator = CsvValidator::new(0).has_headers(false).delimiter(b';');
+        let (valid, invalid, counts) = run(&validator, "a@example.com;x\n@example.com;y\n");
+
+        assert_eq!(counts, (1, 1));
+        assert_eq!(valid, "a@example.com;x\n");
+        assert_eq!(invalid, "@example.com;y;invalid_user_part\n");
+    }
+
+    #[test]
+    fn test_policy_is_applied() {
+        let input = "email\nadmin@[IPv6:fe80::1%eth0]\n";
+
+        let (_, invalid, _) = run(&CsvValidator::new("email"), input);
+        assert!(invalid.ends_with(",ip_zone_id_not_allowed\n"));
+
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        let (_, _, counts) = run(&CsvValidator::new("email").policy(policy), input);
+        assert_eq!(counts, (1, 0));
+    }
+
+    #[test]
+    fn test_byte_order_mark_in_header() {
+        let (_, _, counts) = run(
+            &CsvValidator::new("email"),
+            "\u{FEFF}email\na@example.com\n",
+        );
+        assert_eq!(counts, (1, 0));
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let cases = [
+            (CsvValidator::new("mail"), "mail"),
+            (CsvValidator::new(9), "9"),
+            (CsvValidator::new("email").has_headers(false), "email"),
+        ];
+
+        for (validator, expected) in cases {
+            let result = validator.run(CONTACTS.as_bytes(), Vec::new(), Vec::new());
+            assert!(
+                matches!(&result, Err(CsvError::ColumnNotFound(column)) if column == expected),
+                "{result:?}"
+            );
+        }
+    }
+}
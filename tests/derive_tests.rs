@@ -0,0 +1,188 @@
+#![cfg(feature = "derive")]
+
+use email_syntax_verify_opt::{
+    EmailFieldError, EmailValidationError, ValidateEmail, ValidateEmails, ValidationPolicy,
+};
+
+const ZONES_ALLOWED: ValidationPolicy = ValidationPolicy::new().allow_ip_zone_id(true);
+
+#[derive(ValidateEmails)]
+struct Signup {
+    #[email]
+    email: String,
+    #[email]
+    backup: Option<String>,
+    #[email(policy = "ZONES_ALLOWED")]
+    cc: Vec<String>,
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[derive(ValidateEmails)]
+#[email(policy = "ValidationPolicy::new().allow_ip_zone_id(true)")]
+struct Relay<'a, T> {
+    #[email]
+    host: &'a str,
+    #[email]
+    groups: Vec<Vec<T>>,
+    #[email(policy = "ValidationPolicy::DEFAULT")]
+    strict: Box<str>,
+}
+
+#[derive(ValidateEmails)]
+struct Tuple(
+    #[email] String,
+    #[allow(dead_code)] u32,
+    #[email] Option<Box<str>>,
+);
+
+#[derive(ValidateEmails)]
+struct Unit;
+
+// No trailing comma in the `where` clause, which the bounds are appended to.
+#[rustfmt::skip]
+#[derive(ValidateEmails)]
+struct Bounded<T>
+where
+    T: Clone
+{
+    #[email]
+    email: String,
+    #[email]
+    extra: Vec<T>,
+}
+
+/// Only accepts lowercase addresses.
+struct Lowercase(&'static str);
+
+impl ValidateEmail for Lowercase {
+    fn check_email(&self, policy: ValidationPolicy) -> Result<(), EmailValidationError> {
+        self.0.check_email(policy)?;
+        if self.0.bytes().any(|b| b.is_ascii_uppercase()) {
+            return Err(EmailValidationError::InvalidUserPart);
+        }
+        Ok(())
+    }
+}
+
+#[derive(ValidateEmails)]
+struct Raw<'a> {
+    #[email]
+    bytes: Vec<u8>,
+    #[email]
+    pair: [&'a [u8]; 2],
+    #[email]
+    custom: Option<Lowercase>,
+    #[email]
+    customs: Vec<Lowercase>,
+}
+
+fn errors(
+    result: Result<(), email_syntax_verify_opt::EmailFieldErrors>,
+) -> Vec<(String, EmailValidationError)> {
+    result
+        .unwrap_err()
+        .into_iter()
+        .map(|err: EmailFieldError| (err.path().to_owned(), err.error()))
+        .collect()
+}
+
+#[cfg(test)]
+mod derive_tests {
+    use super::*;
+
+    fn signup() -> Signup {
+        Signup {
+            email: "user@example.com".to_owned(),
+            backup: None,
+            cc: vec![
+                "a@example.com".to_owned(),
+                "b@[IPv6:fe80::1%eth0]".to_owned(),
+            ],
+            name: "not an address".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_valid_struct_passes() {
+        assert_eq!(signup().validate_emails(), Ok(()));
+        assert_eq!(Unit.validate_emails(), Ok(()));
+        let bounded = Bounded {
+            email: "user@example.com".to_owned(),
+            extra: vec!["a@example.com"],
+        };
+        assert_eq!(bounded.validate_emails(), Ok(()));
+    }
+
+    #[test]
+    fn test_errors_carry_field_paths() {
+        let mut invalid = signup();
+        invalid.email = "user.example.com".to_owned();
+        invalid.backup = Some("x@@example.com".to_owned());
+        invalid.cc.push(String::new());
+
+        let result = invalid.validate_emails();
+        assert_eq!(
+            result.as_ref().unwrap_err().to_string(),
+            "email: Email missing @ symbol; backup: Email has multiple @ symbols; \
+             cc[2]: Email is empty"
+        );
+        assert_eq!(
+            errors(result),
+            [
+                ("email".to_owned(), EmailValidationError::NoAtSymbol),
+                ("backup".to_owned(), EmailValidationError::MultipleAtSymbols),
+                ("cc[2]".to_owned(), EmailValidationError::Empty),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_struct_and_field_policies() {
+        let zoned = "admin@[IPv6:fe80::1%eth0]";
+        let relay = Relay {
+            host: zoned,
+            groups: vec![vec![], vec!["ok@example.com", "bad"]],
+            strict: zoned.into(),
+        };
+
+        assert_eq!(
+            errors(relay.validate_emails()),
+            [
+                ("groups[1][1]".to_owned(), EmailValidationError::NoAtSymbol),
+                (
+                    "strict".to_owned(),
+                    EmailValidationError::IpZoneIdNotAllowed
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tuple_struct_paths() {
+        let tuple = Tuple("a@example.com".to_owned(), 7, Some("@x.com".into()));
+        assert_eq!(
+            errors(tuple.validate_emails()),
+            [("2".to_owned(), EmailValidationError::InvalidUserPart)]
+        );
+    }
+
+    #[test]
+    fn test_byte_and_custom_fields() {
+        let raw = Raw {
+            bytes: b"user@example.com".to_vec(),
+            pair: [b"a@example.com", b"b@"],
+            custom: Some(Lowercase("User@example.com")),
+            customs: vec![Lowercase("user.example.com")],
+        };
+
+        assert_eq!(
+            errors(raw.validate_emails()),
+            [
+                ("pair[1]".to_owned(), EmailValidationError::TooShort),
+                ("custom".to_owned(), EmailValidationError::InvalidUserPart),
+                ("customs[0]".to_owned(), EmailValidationError::NoAtSymbol),
+            ]
+        );
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "diesel")]
+
+use diesel::prelude::*;
+use diesel::result::Error;
+use email_syntax_verify_opt::{Email, EmailValidationError};
+
+diesel::table! {
+    users (id) {
+        id -> Integer,
+        email -> Text,
+    }
+}
+
+#[derive(Debug, PartialEq, Queryable, Insertable)]
+#[diesel(table_name = users)]
+struct User {
+    id: i32,
+    email: Email,
+}
+
+fn connection() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+    conn
+}
+
+#[cfg(test)]
+mod diesel_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut conn = connection();
+        let user = User {
+            id: 1,
+            email: Email::parse("user@example.com").unwrap(),
+        };
+
+        diesel::insert_into(users::table)
+            .values(&user)
+            .execute(&mut conn)
+            .unwrap();
+
+        let loaded: User = users::table
+            .filter(users::email.eq(&user.email))
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(loaded, user);
+    }
+
+    #[test]
+    fn test_invalid_stored_value_fails_to_load() {
+        let mut conn = connection();
+        diesel::sql_query("INSERT INTO users (id, email) VALUES (1, 'a@@example.com')")
+            .execute(&mut conn)
+            .unwrap();
+
+        let err = users::table
+            .select(users::email)
+            .first::<Email>(&mut conn)
+            .unwrap_err();
+        let Error::DeserializationError(source) = err else {
+            panic!("expected a deserialization error, got {err:?}");
+        };
+        // Diesel wraps the error with the name of the failing field.
+        let cause =
+            std::iter::successors(Some(&*source as &dyn std::error::Error), |err| err.source())
+                .find_map(|err| err.downcast_ref::<EmailValidationError>());
+        assert_eq!(cause, Some(&EmailValidationError::MultipleAtSymbols));
+    }
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "alloc")]
+
+use email_syntax_verify_opt::{Email, EmailValidationError, EmailValidator, ValidationPolicy};
+
+#[cfg(test)]
+mod email_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_accessors() {
+        let email: Email = "first.last@example.com".parse().unwrap();
+
+        assert_eq!(email, "first.last@example.com");
+        assert_eq!(email.local_part(), "first.last");
+        assert_eq!(email.domain(), "example.com");
+        assert_eq!(email.to_string(), "first.last@example.com");
+        assert_eq!(String::from(email), "first.last@example.com");
+    }
+
+    #[test]
+    fn test_invalid_input_is_rejected() {
+        assert_eq!(
+            Email::parse("no-at.example.com"),
+            Err(EmailValidationError::NoAtSymbol)
+        );
+        assert_eq!(
+            Email::try_from(String::from("a@-bad.example")),
+            Err(EmailValidationError::InvalidDomainPart)
+        );
+        assert_eq!(Email::try_from(""), Err(EmailValidationError::Empty));
+    }
+
+    #[test]
+    fn test_round_trips_with_borrowed_address() {
+        let borrowed = EmailValidator::parse_str("user@münchen.de").unwrap();
+        let owned = Email::from(borrowed);
+
+        assert_eq!(owned.as_address(), borrowed);
+        assert_eq!(owned.as_address().canonical(), "user@xn--mnchen-3ya.de");
+    }
+
+    #[test]
+    fn test_ip_literal_survives_ownership() {
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        let email = Email::parse_with_policy("admin@[IPv6:fe80::1%eth0]", policy).unwrap();
+
+        let literal = email.as_address().ip_literal().unwrap();
+        assert_eq!(
+            literal.addr(),
+            "fe80::1".parse::<std::net::IpAddr>().unwrap()
+        );
+        assert_eq!(literal.zone_id(), Some("eth0"));
+        assert_eq!(
+            Email::parse("admin@[IPv6:fe80::1%eth0]"),
+            Err(EmailValidationError::IpZoneIdNotAllowed)
+        );
+    }
+}
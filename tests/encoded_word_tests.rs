@@ -0,0 +1,153 @@
+#![cfg(feature = "alloc")]
+
+use email_syntax_verify_opt::encoded_word::{
+    decode_word, encode, encode_if_needed, needs_encoding, Encoding, MAX_ENCODED_WORD_LENGTH,
+};
+use email_syntax_verify_opt::error::EncodedWordError;
+use email_syntax_verify_opt::Mailbox;
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_b_and_q() {
+        let cases = [
+            ("=?UTF-8?B?5byg5LiJ?=", "张三"),
+            ("=?utf-8?b?SGVsbG8sIFdvcmxkIQ==?=", "Hello, World!"),
+            ("=?UTF-8?B?SGk?=", "Hi"),
+            ("=?UTF-8?Q?Caf=C3=A9_au_lait?=", "Café au lait"),
+            ("=?ISO-8859-1?Q?Andr=E9?=", "André"),
+            (
+                "=?iso-8859-1?q?Keld_J=F8rn_Simonsen?=",
+                "Keld Jørn Simonsen",
+            ),
+            ("=?ISO-8859-15?Q?Prix_=A4?=", "Prix €"),
+            ("=?windows-1252?Q?=93quoted=94?=", "\u{201C}quoted\u{201D}"),
+            ("=?US-ASCII*EN?Q?Keith_Moore?=", "Keith Moore"),
+        ];
+
+        for (word, expected) in cases {
+            assert_eq!(decode_word(word).as_deref(), Ok(expected), "`{word}`");
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let cases = [
+            ("plain", EncodedWordError::Malformed),
+            ("=?UTF-8?X?abc?=", EncodedWordError::Malformed),
+            ("=?KOI8-R?B?5byg?=", EncodedWordError::UnsupportedCharset),
+            ("=?UTF-8?B?5by*?=", EncodedWordError::InvalidEncoding),
+            ("=?UTF-8?B?A?=", EncodedWordError::InvalidEncoding),
+            ("=?UTF-8?Q?bad=Z1?=", EncodedWordError::InvalidEncoding),
+            ("=?UTF-8?Q?=FF?=", EncodedWordError::InvalidCharsetData),
+            ("=?US-ASCII?Q?=E9?=", EncodedWordError::InvalidCharsetData),
+        ];
+
+        for (word, expected) in cases {
+            assert_eq!(decode_word(word), Err(expected), "`{word}`");
+        }
+    }
+}
+
+#[cfg(test)]
+mod mailbox_decoding_tests {
+    use super::*;
+
+    #[test]
+    fn test_encoded_display_names() {
+        let cases = [
+            ("=?UTF-8?B?5byg5LiJ?= <zhang@example.cn>", "张三"),
+            (
+                "Mr =?ISO-8859-1?Q?Andr=E9?= Pirard <pirard@example.be>",
+                "Mr André Pirard",
+            ),
+            ("=?UTF-8?Q?a?= =?UTF-8?Q?b?= <ab@example.com>", "ab"),
+            (
+                "=?UTF-8?Q?a?=\r\n =?UTF-8?Q?_b?= c <ab@example.com>",
+                "a b c",
+            ),
+            ("=?KOI8-R?B?5byg?= <x@example.com>", "=?KOI8-R?B?5byg?="),
+        ];
+
+        for (input, expected) in cases {
+            let mailbox = Mailbox::parse(input).unwrap();
+            assert!(mailbox.has_encoded_words(), "`{input}`");
+            assert_eq!(mailbox.display_name(), Some(expected), "`{input}`");
+        }
+    }
+
+    #[test]
+    fn test_encoded_group_name() {
+        let entry =
+            email_syntax_verify_opt::parse_address_list("=?UTF-8?Q?=C3=89quipe?=: a@example.com;")
+                .next()
+                .unwrap()
+                .unwrap();
+        assert_eq!(entry.group(), Some("Équipe"));
+    }
+}
+
+#[cfg(test)]
+mod encode_tests {
+    use super::*;
+
+    const SAMPLES: &[&str] = &[
+        "张三",
+        "André Pirard",
+        "Zoë \"Q\" Smith_?=",
+        "日本語のとても長い表示名はいくつかのエンコードされた単語に分割される必要があります",
+        "😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀",
+    ];
+
+    fn decode_all(encoded: &str) -> String {
+        encoded
+            .split(' ')
+            .map(|word| {
+                assert!(
+                    word.len() <= MAX_ENCODED_WORD_LENGTH,
+                    "`{word}` is too long"
+                );
+                decode_word(word).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        for &text in SAMPLES {
+            for encoding in [Encoding::B, Encoding::Q] {
+                let encoded = encode(text, encoding);
+                assert!(encoded.is_ascii());
+                assert_eq!(decode_all(&encoded), text, "{encoding:?} `{encoded}`");
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_encodings() {
+        assert_eq!(encode("张三", Encoding::B), "=?UTF-8?B?5byg5LiJ?=");
+        assert_eq!(encode("André P", Encoding::Q), "=?UTF-8?Q?Andr=C3=A9_P?=");
+    }
+
+    #[test]
+    fn test_encode_if_needed() {
+        assert!(!needs_encoding("John Doe"));
+        assert!(needs_encoding("Zoë"));
+        assert!(needs_encoding("line\nbreak"));
+
+        assert_eq!(encode_if_needed("John Doe"), "John Doe");
+        assert_eq!(encode_if_needed("Zoë Smith"), "=?UTF-8?Q?Zo=C3=AB_Smith?=");
+        assert_eq!(encode_if_needed("张三"), "=?UTF-8?B?5byg5LiJ?=");
+    }
+
+    #[test]
+    fn test_encoded_output_parses_back() {
+        for &text in SAMPLES {
+            let header = format!("{} <user@example.com>", encode_if_needed(text));
+            let mailbox = Mailbox::parse(&header).unwrap();
+            assert_eq!(mailbox.display_name(), Some(text), "`{header}`");
+        }
+    }
+}
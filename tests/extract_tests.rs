@@ -0,0 +1,245 @@
+use email_syntax_verify_opt::{extract_emails, EmailExtractor, ValidationPolicy};
+
+fn extract(text: &str) -> Vec<&str> {
+    extract_emails(text)
+        .map(|(_, addr)| addr.as_str())
+        .collect()
+}
+
+#[cfg(test)]
+mod extraction_tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_point_into_input() {
+        let text = "Contact alice@example.com or bob@example.org today";
+        let found: Vec<_> = extract_emails(text).collect();
+
+        assert_eq!(found.len(), 2);
+        for (span, addr) in found {
+            assert_eq!(&text[span], addr.as_str());
+        }
+    }
+
+    #[test]
+    fn test_surrounding_punctuation() {
+        let cases = [
+            ("Email me at john@example.com.", "john@example.com"),
+            ("(john@example.com)", "john@example.com"),
+            ("\"john@example.com\",", "john@example.com"),
+            ("'john@example.com';", "john@example.com"),
+            ("see john@example.com! or", "john@example.com"),
+            ("is it john@example.com?", "john@example.com"),
+            ("`john@example.com`", "john@example.com"),
+            ("...john@example.com...", "john@example.com"),
+            ("**john@example.com**", "john@example.com"),
+            ("o'brien@example.ie", "o'brien@example.ie"),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(extract(text), [expected], "`{text}`");
+        }
+    }
+
+    #[test]
+    fn test_angle_brackets_and_mailto() {
+        assert_eq!(
+            extract("From: John Doe <john.doe@example.com>"),
+            ["john.doe@example.com"]
+        );
+        assert_eq!(
+            extract(r#"<a href="mailto:sales@example.com?subject=Hi&cc=boss@example.com">"#),
+            ["sales@example.com", "boss@example.com"]
+        );
+        assert_eq!(
+            extract("MAILTO:support@example.com"),
+            ["support@example.com"]
+        );
+    }
+
+    #[test]
+    fn test_log_line() {
+        let line = "2024-01-01T00:00:00Z WARN auth: login failed user=jane+ops@corp.example.com ip=10.0.0.1";
+        assert_eq!(extract(line), ["jane+ops@corp.example.com"]);
+    }
+
+    #[test]
+    fn test_rejects_non_addresses() {
+        for text in [
+            "ping @channel",
+            "handle@localhost",
+            "a@-bad.com",
+            "user@",
+            "@example.com",
+            "x@y",
+            "no addresses here",
+        ] {
+            assert!(extract(text).is_empty(), "`{text}`");
+        }
+    }
+
+    #[test]
+    fn test_overlong_local_part_is_not_truncated() {
+        let text = format!("{}@example.com", "a".repeat(65));
+        assert!(extract(&text).is_empty());
+    }
+
+    #[test]
+    fn test_adjacent_addresses() {
+        assert_eq!(
+            extract("a@example.com,b@example.com;c@example.com"),
+            ["a@example.com", "b@example.com", "c@example.com"]
+        );
+        assert_eq!(extract("a@example.com@evil.com"), ["a@example.com"]);
+    }
+
+    #[test]
+    fn test_internationalized_domains() {
+        assert_eq!(extract("写信给 info@bücher.de。谢谢"), ["info@bücher.de"]);
+        assert_eq!(extract("user@münchen.de — thanks"), ["user@münchen.de"]);
+    }
+
+    #[test]
+    fn test_ip_literals() {
+        assert_eq!(
+            extract("root at <admin@[192.168.1.1]>"),
+            ["admin@[192.168.1.1]"]
+        );
+
+        let text = "admin@[IPv6:fe80::1%eth0]";
+        assert!(extract(text).is_empty());
+
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        let found: Vec<_> = EmailExtractor::with_policy(text.as_bytes(), policy).collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 0..text.len());
+    }
+
+    #[test]
+    fn test_non_utf8_bytes() {
+        let mut input = b"\xff\xfe garbage ".to_vec();
+        input.extend_from_slice(b"ops@example.com\x80\x81 tail");
+
+        let found: Vec<_> = EmailExtractor::from_bytes(&input).collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 11..26);
+        assert_eq!(found[0].1.as_str(), "ops@example.com");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod deobfuscation_tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn recover(text: &str) -> Vec<String> {
+        extract_emails(text)
+            .deobfuscate()
+            .map(|(_, addr)| addr.into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_bracket_styles() {
+        for text in [
+            "john [at] example [dot] com",
+            "john(at)example.com",
+            "john{at}example{dot}com",
+            "john <at> example <dot> com",
+            "john [ AT ] example [ DOT ] com",
+            "john [@] example [.] com",
+            "john (@) example.com",
+        ] {
+            assert_eq!(recover(text), ["john@example.com"], "`{text}`");
+        }
+    }
+
+    #[test]
+    fn test_bare_words_require_marked_substitution() {
+        assert_eq!(
+            recover("write to john at example [dot] com"),
+            ["john@example.com"]
+        );
+        assert_eq!(
+            recover("write to john [at] example dot com"),
+            ["john@example.com"]
+        );
+        assert_eq!(recover("john at example&#46;com"), ["john@example.com"]);
+        assert_eq!(recover("john @ example.com"), ["john@example.com"]);
+    }
+
+    #[test]
+    fn test_bare_words_in_prose_are_ignored() {
+        for text in [
+            "look at example.com for details",
+            "look at example dot com",
+            "a at b at c dot com",
+            "write to john at example dot com",
+            "meet at noon . Then leave",
+        ] {
+            assert!(recover(text).is_empty(), "`{text}`");
+        }
+    }
+
+    #[test]
+    fn test_other_languages() {
+        for text in [
+            "juan arroba ejemplo (punto) es",
+            "jean [arobase] exemple [point] fr",
+            "mario [chiocciola] esempio punto it",
+            "hans (Klammeraffe) beispiel (punkt) de",
+            "piet apenstaartje voorbeeld [punt] nl",
+            "jan (małpa) przyklad kropka pl",
+            "ivan собака primer [точка] ru",
+            "yamada＠example.jp",
+        ] {
+            let found = recover(text);
+            assert_eq!(found.len(), 1, "`{text}`");
+            assert!(found[0].contains('@'), "`{text}`");
+        }
+    }
+
+    #[test]
+    fn test_html_entities() {
+        for text in [
+            "john&#64;example.com",
+            "john&#x40;example&#46;com",
+            "john&#064;example&#X2E;com",
+            "john&commat;example&period;com",
+        ] {
+            assert_eq!(recover(text), ["john@example.com"], "`{text}`");
+        }
+        assert!(recover("john&#65;example.com").is_empty());
+    }
+
+    #[test]
+    fn test_reports_original_span() {
+        let text = "Mail: john.doe [at] mail [dot] example [dot] org, thanks";
+        let found: Vec<_> = extract_emails(text).deobfuscate().collect();
+
+        assert_eq!(found.len(), 1);
+        let (span, address) = &found[0];
+        assert_eq!(
+            &text[span.clone()],
+            "john.doe [at] mail [dot] example [dot] org"
+        );
+        assert_eq!(address, "john.doe@mail.example.org");
+        assert!(matches!(address, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_verbatim_addresses_are_borrowed() {
+        let text = "plain@example.com and hidden [at] example [dot] com";
+        let found: Vec<_> = extract_emails(text).deobfuscate().collect();
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, 0..17);
+        assert!(matches!(found[0].1, Cow::Borrowed("plain@example.com")));
+        assert_eq!(found[1].1, "hidden@example.com");
+    }
+
+    #[test]
+    fn test_deobfuscation_is_opt_in() {
+        assert!(extract("john [at] example [dot] com").is_empty());
+    }
+}
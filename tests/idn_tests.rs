@@ -0,0 +1,218 @@
+use email_syntax_verify_opt::idn::to_ascii;
+use email_syntax_verify_opt::{EmailValidationError, EmailValidator};
+
+fn ascii(domain: &str) -> String {
+    to_ascii(domain).unwrap().to_string()
+}
+
+#[cfg(test)]
+mod punycode_tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc3492_samples() {
+        let cases = [
+            ("ليهمابتكلموشعربي؟", "xn--egbpdaj6bu4bxfgehfvwxn"),
+            ("他们为什么不说中文", "xn--ihqwcrb4cv8a8dqg056pqjye"),
+            ("そのスピードで", "xn--d9juau41awczczp"),
+            (
+                "почемужеонинеговорятпорусски",
+                "xn--b1abfaaepdrnnbgefbadotcwatmq2g4l",
+            ),
+            ("3年b組金八先生", "xn--3b-ww4c5e180e575a65lsy2b"),
+        ];
+
+        for (label, expected) in cases {
+            assert_eq!(ascii(label), expected, "{label}");
+        }
+    }
+
+    #[test]
+    fn test_domains() {
+        let cases = [
+            ("münchen.de", "xn--mnchen-3ya.de"),
+            ("bücher.example.com", "xn--bcher-kva.example.com"),
+            ("ドメイン名例.jp", "xn--eckwd4c7cu47r2wf.jp"),
+            ("example.com", "example.com"),
+        ];
+
+        for (domain, expected) in cases {
+            assert_eq!(ascii(domain), expected, "{domain}");
+        }
+    }
+
+    #[test]
+    fn test_mapping() {
+        assert_eq!(ascii("MÜNCHEN.DE"), "xn--mnchen-3ya.de");
+        assert_eq!(ascii("bücher。de"), "xn--bcher-kva.de");
+        assert_eq!(ascii("ｂüｃｈｅｒ．ｄｅ"), "xn--bcher-kva.de");
+    }
+
+    #[test]
+    fn test_rejected_domains() {
+        let too_long = ["bücher"; 20].join(".");
+        let cases = [
+            "\u{0301}bücher.de",
+            "xn--bücher.de",
+            "bü\u{E000}cher.de",
+            "\u{05D0}\u{05D1}.1a",
+            "x\u{0627}1.com",
+            too_long.as_str(),
+        ];
+
+        for domain in cases {
+            assert_eq!(
+                to_ascii(domain),
+                Err(EmailValidationError::IdnProcessingFailed),
+                "{domain}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn test_rejected_outside_stack_subset() {
+        let cases = [
+            "bü cher.de",
+            "bü\u{200B}cher.de",
+            "bü_cher.de",
+            "☕.us",
+            "bücher.xn--p1ai",
+            "mu\u{0308}nchen.de",
+            "\u{2177}.com",
+            "\u{FB00}.com",
+            "\u{24D0}.com",
+            "\u{2F00}.com",
+            "aउदाहरणb.com",
+            "example.भारत",
+        ];
+
+        for domain in cases {
+            assert_eq!(
+                to_ascii(domain),
+                Err(EmailValidationError::IdnProcessingFailed),
+                "{domain}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_idna_fallback() {
+        let cases = [
+            ("user@aउदाहरणb.com", "user@xn--ab-lmf8hoa4d2cwb.com"),
+            ("user@shopதமிழ்1.com", "user@xn--shop1-b8nzd4b2fpg.com"),
+            ("user@a𠀀b.com", "user@xn--ab-2x43a.com"),
+            ("user@aｶﾞb.jp", "user@xn--ab-ch4a.jp"),
+            ("user@aሰላምb.et", "user@xn--ab-88k4dre.et"),
+            ("user@example.भारत", "user@example.xn--h2brj9c"),
+            ("user@example.இந்தியா", "user@example.xn--xkc2dl3a5ee0h"),
+            ("user@mu\u{0308}nchen.de", "user@xn--mnchen-3ya.de"),
+        ];
+
+        for (address, expected) in cases {
+            let email = EmailValidator::parse_str(address).unwrap();
+            assert_eq!(email.canonical(), expected, "{address}");
+        }
+    }
+
+    #[test]
+    fn test_validator_uses_ascii_form() {
+        assert!(EmailValidator::parse_str("user@mañana.example.com").is_ok());
+        assert!(EmailValidator::parse_str("user@BÜCHER.DE").is_ok());
+        for address in ["user@bü\u{E000}cher.de", "user@x\u{0627}1.com"] {
+            assert_eq!(
+                EmailValidator::parse_str(address).unwrap_err(),
+                EmailValidationError::IdnProcessingFailed,
+                "{address}"
+            );
+        }
+        assert_eq!(
+            EmailValidator::parse_str("user@bü-.de").unwrap_err(),
+            EmailValidationError::InvalidDomainPart
+        );
+    }
+}
+
+/// Asserts that whatever `to_ascii` accepts, `idna` encodes the same way.
+fn assert_agrees_with_idna(domain: &str) {
+    if let Ok(ours) = to_ascii(domain) {
+        assert_eq!(
+            idna::domain_to_ascii(domain).ok().as_deref(),
+            Some(ours.as_str()),
+            "{domain:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod idna_differential_tests {
+    use super::*;
+
+    const MARKS: [char; 4] = ['\u{0300}', '\u{0E31}', '\u{0E38}', '\u{0E48}'];
+
+    #[test]
+    #[ignore = "walks every code point; run with --ignored"]
+    fn test_every_code_point() {
+        for c in (0x80..=0x10FFFF).filter_map(char::from_u32) {
+            assert_agrees_with_idna(&format!("{c}.com"));
+            assert_agrees_with_idna(&format!("a{c}b.com"));
+            assert_agrees_with_idna(&format!("\u{0E01}{c}.com"));
+        }
+    }
+
+    #[test]
+    fn test_mark_sequences() {
+        let marks = ('\u{0300}'..='\u{036F}').chain('\u{0E31}'..='\u{0E4E}');
+        for first in marks.clone() {
+            for second in marks.clone() {
+                assert_agrees_with_idna(&format!("a{first}{second}.com"));
+                assert_agrees_with_idna(&format!("\u{0E01}{first}{second}.com"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bidi_combinations() {
+        let alphabet = [
+            'a', 'Z', '1', '-', '\u{00E9}', '\u{05D0}', '\u{0628}', '\u{0661}', '\u{06F1}',
+            '\u{0E01}', '\u{4E2D}', MARKS[0], MARKS[2],
+        ];
+        let mut labels = vec![String::new()];
+        for _ in 0..3 {
+            let longer: Vec<String> = labels
+                .iter()
+                .flat_map(|label| alphabet.iter().map(move |c| format!("{label}{c}")))
+                .collect();
+            labels.extend(longer);
+        }
+        labels.retain(|label| !label.is_empty());
+        labels.dedup();
+
+        for label in &labels {
+            for other in ["com", "1a", "\u{05D0}\u{05D1}", "\u{0628}1"] {
+                assert_agrees_with_idna(&format!("{label}.{other}"));
+                assert_agrees_with_idna(&format!("{other}.{label}"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reported_divergences() {
+        let cases = [
+            "x\u{0627}1.com",
+            "mu\u{0308}nchen.de",
+            "\u{2177}.com",
+            "\u{FB00}.com",
+            "\u{24D0}.com",
+            "\u{2F00}.com",
+            "stra\u{00DF}e.de",
+            "\u{1E9E}.de",
+            "\u{0130}stanbul.tr",
+        ];
+
+        for domain in cases {
+            assert_agrees_with_idna(domain);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use email_syntax_verify_opt::{
+    EmailValidationError, EmailValidator, IpLiteral, ValidateEmail, ValidationPolicy,
+};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[cfg(test)]
+mod ip_literal_tests {
+    use super::*;
+
+    #[test]
+    fn test_parsed_ipv4_literal() {
+        let address = EmailValidator::parse_str("user@[192.168.0.1]").unwrap();
+        assert_eq!(
+            address.ip_literal(),
+            Some(IpLiteral::V4(Ipv4Addr::new(192, 168, 0, 1)))
+        );
+        assert_eq!(address.local_part(), "user");
+        assert_eq!(address.domain(), "[192.168.0.1]");
+    }
+
+    #[test]
+    fn test_tagged_ipv6_literal() {
+        for email in ["user@[IPv6:2001:db8::1]", "user@[ipv6:2001:db8::1]"] {
+            let address = EmailValidator::parse_str(email).unwrap();
+            assert_eq!(
+                address.ip_literal(),
+                Some(IpLiteral::V6 {
+                    addr: "2001:db8::1".parse::<Ipv6Addr>().unwrap(),
+                    zone_id: None,
+                })
+            );
+        }
+
+        assert!("user@[IPv6:2001:db8::1]".validate_email());
+        assert!(!"user@[IPv6:127.0.0.1]".validate_email());
+    }
+
+    #[test]
+    fn test_plain_domain_has_no_ip_literal() {
+        let address = EmailValidator::parse_str("user@example.com").unwrap();
+        assert!(!address.is_ip_literal());
+        assert_eq!(address.ip_literal(), None);
+    }
+}
+
+#[cfg(test)]
+mod zone_id_tests {
+    use super::*;
+
+    const ZONED_EMAILS: &[&str] = &[
+        "user@[IPv6:fe80::1%eth0]",
+        "user@[fe80::1%eth0]",
+        "user@[fe80::1%1]",
+        "user@[IPv6:fe80::abcd%en0.100]",
+    ];
+
+    #[test]
+    fn test_zone_id_rejected_by_default() {
+        for &email in ZONED_EMAILS {
+            assert!(!email.validate_email(), "`{email}` should be rejected");
+            assert_eq!(
+                EmailValidator::parse_str(email),
+                Err(EmailValidationError::IpZoneIdNotAllowed),
+                "`{email}` should report a zone id error"
+            );
+        }
+    }
+
+    #[test]
+    fn test_zone_id_accepted_by_policy() {
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+
+        for &email in ZONED_EMAILS {
+            assert!(
+                EmailValidator::validate_with_policy(email.as_bytes(), policy),
+                "`{email}` should be accepted"
+            );
+        }
+
+        let address =
+            EmailValidator::parse_with_policy(b"user@[IPv6:fe80::1%eth0]", policy).unwrap();
+        let literal = address.ip_literal().unwrap();
+        assert_eq!(literal.zone_id(), Some("eth0"));
+        assert_eq!(literal.addr(), "fe80::1".parse::<Ipv6Addr>().unwrap());
+    }
+
+    #[test]
+    fn test_malformed_zone_id() {
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        let cases = [
+            "user@[fe80::1%]",
+            "user@[fe80::1%eth 0]",
+            "user@[fe80::1%eth0%1]",
+            "user@[fe80::1%eth0/64]",
+            "user@[127.0.0.1%eth0]",
+            "user@[fe80::1%aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa]",
+        ];
+
+        for email in cases {
+            assert_eq!(
+                EmailValidator::parse_with_policy(email.as_bytes(), policy),
+                Err(EmailValidationError::InvalidIpLiteral),
+                "`{email}` should be an invalid literal"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_address_reported_before_zone() {
+        assert_eq!(
+            EmailValidator::parse_str("user@[fe80::12345%eth0]"),
+            Err(EmailValidationError::InvalidIpLiteral)
+        );
+    }
+}
+
+#[cfg(test)]
+mod error_reporting_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_kinds() {
+        let cases = [
+            ("", EmailValidationError::Empty),
+            ("a@", EmailValidationError::TooShort),
+            ("plainaddress", EmailValidationError::NoAtSymbol),
+            ("a@b@example.com", EmailValidationError::MultipleAtSymbols),
+            ("@example.com", EmailValidationError::InvalidUserPart),
+            ("user@", EmailValidationError::InvalidDomainPart),
+            (".user@example.com", EmailValidationError::InvalidUserPart),
+            ("user@-example.com", EmailValidationError::InvalidDomainPart),
+            ("user@[127.0.0.256]", EmailValidationError::InvalidIpLiteral),
+        ];
+
+        for (email, expected) in cases {
+            assert_eq!(
+                EmailValidator::parse_str(email),
+                Err(expected),
+                "`{email}` reported the wrong error"
+            );
+        }
+
+        let too_long = format!("a@{}.com", "b".repeat(320));
+        assert_eq!(
+            EmailValidator::parse_str(&too_long),
+            Err(EmailValidationError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_non_utf8_domain_is_rejected() {
+        assert!(!EmailValidator::validate(b"user@ex\xffample.com"));
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            EmailValidationError::IpZoneIdNotAllowed.to_string(),
+            EmailValidationError::IpZoneIdNotAllowed.as_str()
+        );
+    }
+}
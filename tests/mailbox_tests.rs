@@ -0,0 +1,210 @@
+#![cfg(feature = "alloc")]
+
+use email_syntax_verify_opt::encoded_word::is_encoded_word;
+use email_syntax_verify_opt::{
+    format_display_name, EmailValidationError, EmailValidator, Mailbox, MailboxError,
+    ValidationPolicy,
+};
+use std::borrow::Cow;
+
+#[cfg(test)]
+mod mailbox_parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_quoted_display_name() {
+        let mailbox = Mailbox::parse("\"Doe, John\" <john@example.com>").unwrap();
+        assert_eq!(mailbox.display_name(), Some("Doe, John"));
+        assert_eq!(mailbox.address().as_str(), "john@example.com");
+        assert_eq!(mailbox.address().local_part(), "john");
+        assert_eq!(mailbox.address().domain(), "example.com");
+    }
+
+    #[test]
+    fn test_bare_phrase_display_name() {
+        let cases = [
+            ("John Doe <john@example.com>", "John Doe"),
+            ("John Q. Public <jqp@example.com>", "John Q. Public"),
+            ("  John \t  Doe   <john@example.com>  ", "John Doe"),
+            (
+                "John \"the Man\" Doe <john@example.com>",
+                "John the Man Doe",
+            ),
+            ("Zoë Ångström <zoe@example.se>", "Zoë Ångström"),
+        ];
+
+        for (input, expected) in cases {
+            let mailbox = Mailbox::parse(input).unwrap();
+            assert_eq!(mailbox.display_name(), Some(expected), "`{input}`");
+        }
+    }
+
+    #[test]
+    fn test_angle_addr_without_display_name() {
+        for input in [
+            "<john@example.com>",
+            "  < john@example.com >  ",
+            "john@example.com",
+        ] {
+            let mailbox = Mailbox::parse(input).unwrap();
+            assert_eq!(mailbox.display_name(), None, "`{input}`");
+            assert_eq!(mailbox.address().as_str(), "john@example.com");
+        }
+    }
+
+    #[test]
+    fn test_quoted_pairs_are_unescaped() {
+        let mailbox = Mailbox::parse(r#""C:\\Users \"admin\"" <admin@example.com>"#).unwrap();
+        assert_eq!(mailbox.display_name(), Some(r#"C:\Users "admin""#));
+    }
+
+    #[test]
+    fn test_policy_applies_to_addr_spec() {
+        let input = "Router <admin@[IPv6:fe80::1%eth0]>";
+        assert_eq!(
+            Mailbox::parse(input),
+            Err(MailboxError::InvalidAddress(
+                EmailValidationError::IpZoneIdNotAllowed
+            ))
+        );
+
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        let mailbox = Mailbox::parse_with_policy(input, policy).unwrap();
+        assert_eq!(
+            mailbox.address().ip_literal().unwrap().zone_id(),
+            Some("eth0")
+        );
+    }
+
+    #[test]
+    fn test_rejects_lists_and_groups() {
+        let cases = [
+            ("", MailboxError::Empty),
+            ("   ", MailboxError::Empty),
+            ("a@x.com, b@y.org", MailboxError::UnexpectedCharacter),
+            ("team: a@x.com;", MailboxError::UnexpectedCharacter),
+            (
+                "John <a@x.com> <b@y.org>",
+                MailboxError::UnexpectedCharacter,
+            ),
+            ("John <a@x.com", MailboxError::UnterminatedAngleAddr),
+            ("\"John <a@x.com>", MailboxError::UnterminatedQuotedString),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(Mailbox::parse(input), Err(expected), "`{input}`");
+        }
+    }
+}
+
+#[cfg(test)]
+mod encoded_word_detection_tests {
+    use super::*;
+
+    #[test]
+    fn test_is_encoded_word() {
+        assert!(is_encoded_word("=?UTF-8?B?5byg5LiJ?="));
+        assert!(is_encoded_word("=?iso-8859-1?q?Andr=E9?="));
+        assert!(is_encoded_word("=?UTF-8*en?Q?Hello?="));
+
+        assert!(!is_encoded_word("=?UTF-8?X?abc?="));
+        assert!(!is_encoded_word("=??B?abc?="));
+        assert!(!is_encoded_word("=?UTF-8?B?abc"));
+        assert!(!is_encoded_word("=?UTF-8?B?a?c?="));
+        assert!(!is_encoded_word("plain"));
+        assert!(!is_encoded_word(&format!("=?UTF-8?B?{}?=", "A".repeat(70))));
+    }
+
+    #[test]
+    fn test_mailbox_detects_encoded_words() {
+        let mailbox = Mailbox::parse("=?UTF-8?B?5byg5LiJ?= <zhang@example.cn>").unwrap();
+        assert!(mailbox.has_encoded_words());
+
+        let mailbox =
+            Mailbox::parse("Mr =?ISO-8859-1?Q?Andr=E9?= Pirard <pirard@example.be>").unwrap();
+        assert!(mailbox.has_encoded_words());
+    }
+
+    #[test]
+    fn test_quoted_encoded_words_are_literal() {
+        let mailbox = Mailbox::parse("\"=?UTF-8?B?5byg5LiJ?=\" <zhang@example.cn>").unwrap();
+        assert!(!mailbox.has_encoded_words());
+        assert_eq!(mailbox.display_name(), Some("=?UTF-8?B?5byg5LiJ?="));
+
+        let mailbox = Mailbox::parse("John Doe <john@example.com>").unwrap();
+        assert!(!mailbox.has_encoded_words());
+    }
+}
+
+#[cfg(test)]
+mod mailbox_format_tests {
+    use super::*;
+
+    const DISPLAY_NAMES: &[&str] = &[
+        "John Doe",
+        "Doe, John",
+        "John Q. Public",
+        "Dwayne \"The Rock\" Johnson",
+        "C:\\Users\\admin",
+        "  padded  ",
+        "double  space",
+        "tab\tseparated",
+        "<script>@evil;",
+        "(not a comment)",
+        "=?UTF-8?B?5byg5LiJ?=",
+        "",
+        "张三",
+        "Zoë Ångström",
+        "line\r\nbreak",
+        "日本語のとても長い表示名はいくつかのエンコードされた単語に分割される必要があります",
+    ];
+
+    #[test]
+    fn test_format_display_name() {
+        assert_eq!(format_display_name("John Doe"), "John Doe");
+        assert_eq!(format_display_name("Doe, John"), "\"Doe, John\"");
+        assert_eq!(format_display_name("John Q. Public"), "\"John Q. Public\"");
+        assert_eq!(format_display_name("say \"hi\""), r#""say \"hi\"""#);
+        assert_eq!(format_display_name("a\\b"), r#""a\\b""#);
+        assert_eq!(format_display_name("张三"), "=?UTF-8?B?5byg5LiJ?=");
+        assert!(matches!(format_display_name("John Doe"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_display_formats() {
+        let address = EmailValidator::parse_str("john@example.com").unwrap();
+
+        let mailbox = Mailbox::new(None, address);
+        assert_eq!(mailbox.to_string(), "john@example.com");
+
+        let mailbox = Mailbox::new(Some("Doe, John".into()), address);
+        assert_eq!(mailbox.to_string(), "\"Doe, John\" <john@example.com>");
+    }
+
+    #[test]
+    fn test_format_round_trips() {
+        let addresses = [
+            "john@example.com",
+            "user+tag@sub.example.org",
+            "root@[IPv6:2001:db8::1]",
+            "info@bücher.de",
+        ];
+
+        for address in addresses {
+            let address = EmailValidator::parse_str(address).unwrap();
+            for &name in DISPLAY_NAMES {
+                let original = Mailbox::new(Some(name.into()), address);
+                let header = original.to_string();
+                assert!(
+                    !header.contains(['\r', '\n']),
+                    "`{header}` is not header-safe"
+                );
+
+                let parsed = Mailbox::parse(&header)
+                    .unwrap_or_else(|err| panic!("`{header}` failed to parse: {err}"));
+                assert_eq!(parsed.display_name(), Some(name), "`{header}`");
+                assert_eq!(parsed.address(), address, "`{header}`");
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "alloc")]
+
+use email_syntax_verify_opt::{
+    EmailValidationError, EmailValidator, MailtoBuilder, MailtoError, MailtoUri,
+};
+
+#[cfg(test)]
+mod mailto_parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_mailto() {
+        let uri = MailtoUri::parse("mailto:chris@example.com").unwrap();
+        assert_eq!(uri.to(), ["chris@example.com"]);
+        assert!(uri.cc().is_empty());
+        assert_eq!(uri.subject(), None);
+    }
+
+    #[test]
+    fn test_multiple_recipients_and_headers() {
+        let uri = MailtoUri::parse(
+            "MAILTO:joe@example.com,%20bob@example.org?cc=bob@example.com,sue@example.net\
+             &bcc=audit@example.com&subject=Hello%20World%21&body=Line%201%0D%0ALine%202\
+             &In-Reply-To=%3C3469A91.D10AF4C@example.com%3E",
+        )
+        .unwrap();
+
+        assert_eq!(uri.to(), ["joe@example.com", "bob@example.org"]);
+        assert_eq!(uri.cc(), ["bob@example.com", "sue@example.net"]);
+        assert_eq!(uri.bcc(), ["audit@example.com"]);
+        assert_eq!(uri.subject(), Some("Hello World!"));
+        assert_eq!(uri.body(), Some("Line 1\r\nLine 2"));
+        assert_eq!(
+            uri.headers(),
+            [(
+                "In-Reply-To".to_owned(),
+                "<3469A91.D10AF4C@example.com>".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_percent_encoded_addresses() {
+        let uri = MailtoUri::parse(
+            "mailto:user%2Btag%40example.com?to=%E7%94%A8%E6%88%B7@b%C3%BCcher.de",
+        );
+        assert_eq!(
+            uri,
+            Err(MailtoError::InvalidAddress(
+                EmailValidationError::InvalidUserPart
+            ))
+        );
+
+        let uri =
+            MailtoUri::parse("mailto:user%2Btag%40example.com?to=info@b%C3%BCcher.de").unwrap();
+        assert_eq!(uri.to(), ["user+tag@example.com", "info@bücher.de"]);
+    }
+
+    #[test]
+    fn test_recipients_only_in_headers() {
+        let uri = MailtoUri::parse("mailto:?to=joe@example.com&subject=hi").unwrap();
+        assert_eq!(uri.to(), ["joe@example.com"]);
+        assert_eq!(uri.subject(), Some("hi"));
+    }
+
+    #[test]
+    fn test_plus_is_not_a_space() {
+        let uri = MailtoUri::parse("mailto:a@example.com?subject=1+1").unwrap();
+        assert_eq!(uri.subject(), Some("1+1"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("http://example.com", MailtoError::MissingScheme),
+            ("mailto", MailtoError::MissingScheme),
+            (
+                "mailto:a@example.com?subject=%ZZ",
+                MailtoError::InvalidPercentEncoding,
+            ),
+            (
+                "mailto:a@example.com?subject=%4",
+                MailtoError::InvalidPercentEncoding,
+            ),
+            ("mailto:a@example.com?subject=%FF", MailtoError::InvalidUtf8),
+            ("mailto:a@example.com?=value", MailtoError::InvalidHeader),
+            (
+                "mailto:not-an-address",
+                MailtoError::InvalidAddress(EmailValidationError::NoAtSymbol),
+            ),
+            (
+                "mailto:a@example.com?cc=bad@-example.com",
+                MailtoError::InvalidAddress(EmailValidationError::InvalidDomainPart),
+            ),
+        ];
+
+        for (uri, expected) in cases {
+            assert_eq!(MailtoUri::parse(uri), Err(expected), "`{uri}`");
+        }
+    }
+}
+
+#[cfg(test)]
+mod mailto_builder_tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_percent_encodes() {
+        let uri = MailtoBuilder::new()
+            .to(EmailValidator::parse_str("user+tag@example.com").unwrap())
+            .to(EmailValidator::parse_str("a%b@example.com").unwrap())
+            .cc(EmailValidator::parse_str("boss@example.com").unwrap())
+            .subject("Q&A: 50% off? #deal")
+            .body("first line\nsecond line")
+            .header("X-Mailer", "ünïcode")
+            .build();
+
+        assert_eq!(
+            uri.to_string(),
+            "mailto:user+tag@example.com,a%25b@example.com?cc=boss@example.com\
+             &subject=Q%26A:%2050%25%20off%3F%20%23deal&body=first%20line%0D%0Asecond%20line\
+             &X-Mailer=%C3%BCn%C3%AFcode"
+        );
+    }
+
+    #[test]
+    fn test_builder_round_trips() {
+        let built = MailtoBuilder::new()
+            .to(EmailValidator::parse_str("joe@example.com").unwrap())
+            .to(EmailValidator::parse_str("info@bücher.de").unwrap())
+            .bcc(EmailValidator::parse_str("audit@example.com").unwrap())
+            .subject("Grüße & = ? / # %")
+            .body("a\r\nb\nc")
+            .build();
+
+        let parsed = MailtoUri::parse(&built.to_string()).unwrap();
+        assert_eq!(parsed, built);
+        assert_eq!(parsed.body(), Some("a\r\nb\r\nc"));
+    }
+
+    #[test]
+    fn test_empty_builder() {
+        assert_eq!(MailtoBuilder::new().build().to_string(), "mailto:");
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "rayon")]
+
+use email_syntax_verify_opt::parallel::par_validate_with_policy;
+use email_syntax_verify_opt::{
+    par_parse, par_validate, EmailValidationError, EmailValidator, ParallelValidateEmail,
+    ValidationPolicy,
+};
+use rayon::prelude::*;
+
+fn sample_emails(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| match i % 4 {
+            0 => format!("user{i}@example.com"),
+            1 => format!("user{i}.example.com"),
+            2 => format!("first.last+{i}@sub.example.org"),
+            _ => format!("user{i}@-bad.example"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod slice_tests {
+    use super::*;
+
+    #[test]
+    fn test_par_validate_preserves_order() {
+        let emails = sample_emails(10_000);
+        let sequential = EmailValidator::validate_batch(&emails);
+
+        assert_eq!(par_validate(&emails), sequential);
+        assert_eq!(sequential.iter().filter(|&&valid| valid).count(), 5_000);
+    }
+
+    #[test]
+    fn test_par_parse_reports_reasons() {
+        let emails = ["a@example.com", "no-at.example.com", "b@-bad.example", ""];
+        let results = par_parse(&emails);
+
+        assert_eq!(results[0].map(|addr| addr.domain()), Ok("example.com"));
+        assert_eq!(results[1], Err(EmailValidationError::NoAtSymbol));
+        assert_eq!(results[2], Err(EmailValidationError::InvalidDomainPart));
+        assert_eq!(results[3], Err(EmailValidationError::Empty));
+    }
+
+    #[test]
+    fn test_policy_is_applied() {
+        let emails = ["admin@[IPv6:fe80::1%eth0]"];
+        assert_eq!(par_validate(&emails), [false]);
+
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        assert_eq!(par_validate_with_policy(&emails, policy), [true]);
+    }
+}
+
+#[cfg(test)]
+mod adapter_tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_emails_adapter() {
+        let emails = sample_emails(1_000);
+        let valid: Vec<String> = emails
+            .clone()
+            .into_par_iter()
+            .validate_emails()
+            .filter_map(|(email, valid)| valid.then_some(email))
+            .collect();
+
+        assert_eq!(valid.len(), 500);
+        assert!(valid
+            .iter()
+            .all(|email| EmailValidator::validate_str(email)));
+    }
+
+    #[test]
+    fn test_check_emails_adapter_counts_reasons() {
+        let text = "ok@example.com\nbroken\nalso.ok@example.org\nx@@example.com\n";
+        let mut failures: Vec<(&str, EmailValidationError)> = text
+            .par_lines()
+            .check_emails()
+            .filter_map(|(line, result)| result.err().map(|err| (line, err)))
+            .collect();
+        failures.sort_unstable_by_key(|&(line, _)| line);
+
+        assert_eq!(
+            failures,
+            [
+                ("broken", EmailValidationError::NoAtSymbol),
+                ("x@@example.com", EmailValidationError::MultipleAtSymbols),
+            ]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "serde")]
+
+use email_syntax_verify_opt::Email;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Signup {
+    email: Email,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Contact {
+    #[serde(with = "email_syntax_verify_opt::serde::string")]
+    email: String,
+}
+
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_email_round_trip() {
+        let signup: Signup = serde_json::from_str(r#"{"email":"user@example.com"}"#).unwrap();
+        assert_eq!(signup.email, "user@example.com");
+        assert_eq!(
+            serde_json::to_string(&signup).unwrap(),
+            r#"{"email":"user@example.com"}"#
+        );
+    }
+
+    #[test]
+    fn test_invalid_email_is_rejected() {
+        let err = serde_json::from_str::<Signup>(r#"{"email":"user.example.com"}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid email address: Email missing @ symbol at line 1 column 27"
+        );
+
+        assert!(serde_json::from_str::<Signup>(r#"{"email":42}"#).is_err());
+    }
+
+    #[test]
+    fn test_escaped_input_is_validated_after_unescaping() {
+        let signup: Signup = serde_json::from_str(r#"{"email":"user\u0040example.com"}"#).unwrap();
+        assert_eq!(signup.email, "user@example.com");
+    }
+
+    #[test]
+    fn test_string_field_helper() {
+        let contact: Contact = serde_json::from_str(r#"{"email":"a@example.org"}"#).unwrap();
+        assert_eq!(contact.email, "a@example.org");
+        assert_eq!(
+            serde_json::to_string(&contact).unwrap(),
+            r#"{"email":"a@example.org"}"#
+        );
+
+        let err = serde_json::from_str::<Contact>(r#"{"email":"a@@example.org"}"#).unwrap_err();
+        assert!(err.to_string().starts_with("invalid email address: "));
+    }
+}
@@ -0,0 +1,147 @@
+use email_syntax_verify_opt::simd::{
+    all_domain_chars, all_user_chars, find_byte, has_dot_run, rfind_byte,
+};
+use email_syntax_verify_opt::validate_email;
+
+const USER_SPECIALS: &[u8] = b".!#$%&'*+/=?^_`{|}~-";
+
+fn is_user_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || USER_SPECIALS.contains(&byte)
+}
+
+fn is_domain_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'.'
+}
+
+/// Deterministic xorshift generator so failures are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn pick(&mut self, alphabet: &[u8]) -> u8 {
+        alphabet[(self.next() % alphabet.len() as u64) as usize]
+    }
+}
+
+#[cfg(test)]
+mod simd_equivalence_tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_search_at_every_position() {
+        for len in 0..100 {
+            let mut bytes = vec![b'a'; len];
+            assert_eq!(find_byte(&bytes, b'@'), None);
+            assert_eq!(rfind_byte(&bytes, b'@'), None);
+
+            for pos in 0..len {
+                bytes[pos] = b'@';
+                assert_eq!(find_byte(&bytes, b'@'), Some(pos), "len {len}");
+                assert_eq!(rfind_byte(&bytes, b'@'), Some(pos), "len {len}");
+
+                if pos > 0 {
+                    bytes[0] = b'@';
+                    assert_eq!(find_byte(&bytes, b'@'), Some(0), "len {len}");
+                    assert_eq!(rfind_byte(&bytes, b'@'), Some(pos), "len {len}");
+                    bytes[0] = b'a';
+                }
+                bytes[pos] = b'a';
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_byte_value_is_classified() {
+        for len in [1, 15, 16, 17, 31, 32, 33, 64, 70] {
+            for pos in [0, len / 2, len - 1] {
+                for byte in 0..=u8::MAX {
+                    let mut user = vec![b'a'; len];
+                    user[pos] = byte;
+                    assert_eq!(
+                        all_user_chars(&user),
+                        is_user_char(byte),
+                        "byte {byte:#04x} len {len} pos {pos}"
+                    );
+
+                    let mut domain = vec![b'a'; len];
+                    domain[pos] = byte;
+                    assert_eq!(
+                        all_domain_chars(&domain),
+                        is_domain_char(byte),
+                        "byte {byte:#04x} len {len} pos {pos}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dot_runs_at_every_position() {
+        for len in 2..100 {
+            let mut bytes = b"a.".repeat(len)[..len].to_vec();
+            assert!(!has_dot_run(&bytes), "len {len}");
+
+            for pos in 0..len - 1 {
+                let saved = [bytes[pos], bytes[pos + 1]];
+                bytes[pos] = b'.';
+                bytes[pos + 1] = b'.';
+                assert!(has_dot_run(&bytes), "len {len} pos {pos}");
+                bytes[pos] = saved[0];
+                bytes[pos + 1] = saved[1];
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_inputs_match_scalar() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+        let alphabet: Vec<u8> = b"ab.-@9_+ \xC3\xA9".to_vec();
+
+        for _ in 0..20_000 {
+            let len = (rng.next() % 80) as usize;
+            let bytes: Vec<u8> = (0..len).map(|_| rng.pick(&alphabet)).collect();
+
+            assert_eq!(
+                find_byte(&bytes, b'@'),
+                bytes.iter().position(|&b| b == b'@')
+            );
+            assert_eq!(
+                rfind_byte(&bytes, b'@'),
+                bytes.iter().rposition(|&b| b == b'@')
+            );
+            assert_eq!(
+                all_user_chars(&bytes),
+                bytes.iter().all(|&b| is_user_char(b))
+            );
+            assert_eq!(
+                all_domain_chars(&bytes),
+                bytes.iter().all(|&b| is_domain_char(b))
+            );
+            assert_eq!(
+                has_dot_run(&bytes),
+                bytes.windows(2).any(|pair| pair == b"..")
+            );
+        }
+    }
+
+    #[test]
+    fn test_long_addresses_use_vector_paths() {
+        let local = "first.last+tag".repeat(4);
+        let domain = "sub-domain.example.".repeat(6) + "com";
+        assert!(validate_email(&format!("{local}@{domain}")));
+        assert!(!validate_email(&format!("{local}..x@{domain}")));
+        assert!(!validate_email(&format!("{local}@{domain}-")));
+        assert!(!validate_email(&format!("{local}@-{domain}")));
+        assert!(!validate_email(&format!(
+            "{local}@{}",
+            domain.replace(".example.", "..example.")
+        )));
+        assert!(validate_email(&format!("{local}@bücher-{domain}")));
+    }
+}
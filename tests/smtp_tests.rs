@@ -0,0 +1,209 @@
+use email_syntax_verify_opt::smtp::{parse_forward_path, parse_reverse_path};
+use email_syntax_verify_opt::{
+    EmailValidationError, EnvelopeCommand, SmtpCommand, SmtpPath, SmtpPathError, ValidationPolicy,
+};
+
+#[cfg(test)]
+mod envelope_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_mail_from_with_parameters() {
+        let command =
+            EnvelopeCommand::parse("MAIL FROM:<user@host.example> SIZE=1000 BODY=8BITMIME\r\n")
+                .unwrap();
+
+        assert_eq!(command.command(), SmtpCommand::MailFrom);
+        assert_eq!(
+            command.path().mailbox().map(|addr| addr.as_str()),
+            Some("user@host.example")
+        );
+        assert_eq!(
+            command.parameters().collect::<Vec<_>>(),
+            [("SIZE", Some("1000")), ("BODY", Some("8BITMIME"))]
+        );
+        assert_eq!(command.parameter("size"), Some(Some("1000")));
+        assert_eq!(command.parameter("SMTPUTF8"), None);
+    }
+
+    #[test]
+    fn test_rcpt_to_postmaster() {
+        for line in [
+            "RCPT TO:<postmaster>",
+            "rcpt to:<Postmaster>",
+            "RCPT TO:<POSTMASTER>",
+        ] {
+            let command = EnvelopeCommand::parse(line).unwrap();
+            assert_eq!(command.command(), SmtpCommand::RcptTo);
+            assert_eq!(command.path(), SmtpPath::Postmaster, "`{line}`");
+        }
+
+        let command = EnvelopeCommand::parse("RCPT TO:<Postmaster@example.com>").unwrap();
+        assert!(command.path().mailbox().is_some());
+    }
+
+    #[test]
+    fn test_null_sender() {
+        let command = EnvelopeCommand::parse("MAIL FROM:<> RET=HDRS").unwrap();
+        assert!(command.path().is_null());
+        assert_eq!(command.parameter("RET"), Some(Some("HDRS")));
+
+        assert_eq!(
+            EnvelopeCommand::parse("RCPT TO:<>"),
+            Err(SmtpPathError::NullPathNotAllowed)
+        );
+    }
+
+    #[test]
+    fn test_lenient_whitespace_and_flag_parameters() {
+        let command = EnvelopeCommand::parse("MAIL FROM: <user@example.com>  SMTPUTF8  ").unwrap();
+        assert_eq!(
+            command.parameters().collect::<Vec<_>>(),
+            [("SMTPUTF8", None)]
+        );
+    }
+
+    #[test]
+    fn test_command_errors() {
+        let cases = [
+            ("HELO example.com", SmtpPathError::UnknownCommand),
+            (
+                "MAIL FROM:user@example.com",
+                SmtpPathError::MissingAngleBrackets,
+            ),
+            (
+                "MAIL FROM:<user@example.com",
+                SmtpPathError::MissingAngleBrackets,
+            ),
+            (
+                "MAIL FROM:<user@example.com>SIZE=1",
+                SmtpPathError::InvalidParameter,
+            ),
+            (
+                "MAIL FROM:<user@example.com> SIZE=",
+                SmtpPathError::InvalidParameter,
+            ),
+            (
+                "MAIL FROM:<user@example.com> -BAD",
+                SmtpPathError::InvalidParameter,
+            ),
+            (
+                "MAIL FROM:<user@example.com> A=b=c",
+                SmtpPathError::InvalidParameter,
+            ),
+            (
+                "MAIL FROM:<postmaster>",
+                SmtpPathError::InvalidAddress(EmailValidationError::NoAtSymbol),
+            ),
+            (
+                "RCPT TO:<user@-example.com>",
+                SmtpPathError::InvalidAddress(EmailValidationError::InvalidDomainPart),
+            ),
+        ];
+
+        for (line, expected) in cases {
+            assert_eq!(EnvelopeCommand::parse(line), Err(expected), "`{line}`");
+        }
+    }
+
+    #[test]
+    fn test_path_length_limit() {
+        let local = "a".repeat(64);
+        let domain = format!(
+            "{}.{}.{}.com",
+            "b".repeat(63),
+            "c".repeat(63),
+            "d".repeat(60)
+        );
+        let line = format!("RCPT TO:<{local}@{domain}>");
+        assert_eq!(EnvelopeCommand::parse(&line), Err(SmtpPathError::TooLong));
+    }
+}
+
+#[cfg(test)]
+mod source_route_tests {
+    use super::*;
+
+    #[test]
+    fn test_source_route_stripped_by_default() {
+        let command =
+            EnvelopeCommand::parse("RCPT TO:<@a.example,@b.example:user@c.example>").unwrap();
+        assert_eq!(
+            command.path().mailbox().map(|addr| addr.as_str()),
+            Some("user@c.example")
+        );
+        assert_eq!(command.source_route(), Some("@a.example,@b.example"));
+
+        let command =
+            EnvelopeCommand::parse("MAIL FROM:<@[IPv6:2001:db8::1]:user@c.example>").unwrap();
+        assert_eq!(command.source_route(), Some("@[IPv6:2001:db8::1]"));
+    }
+
+    #[test]
+    fn test_source_route_rejected_by_policy() {
+        let policy = ValidationPolicy::new().strip_source_route(false);
+        assert_eq!(
+            EnvelopeCommand::parse_with_policy("RCPT TO:<@a.example:user@c.example>", policy),
+            Err(SmtpPathError::SourceRouteNotAllowed)
+        );
+        assert!(EnvelopeCommand::parse_with_policy("RCPT TO:<user@c.example>", policy).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_source_routes() {
+        for line in [
+            "RCPT TO:<@a.example>",
+            "RCPT TO:<@a.example,b.example:user@c.example>",
+            "RCPT TO:<@-bad.example:user@c.example>",
+            "RCPT TO:<@:user@c.example>",
+        ] {
+            assert_eq!(
+                EnvelopeCommand::parse(line),
+                Err(SmtpPathError::InvalidSourceRoute),
+                "`{line}`"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod path_tests {
+    use super::*;
+
+    #[test]
+    fn test_bare_paths() {
+        let policy = ValidationPolicy::DEFAULT;
+
+        assert_eq!(parse_reverse_path("<>", policy), Ok(SmtpPath::Null));
+        assert_eq!(
+            parse_forward_path("<Postmaster>", policy),
+            Ok(SmtpPath::Postmaster)
+        );
+        assert!(parse_forward_path("<user@example.com>", policy)
+            .unwrap()
+            .mailbox()
+            .is_some());
+        assert_eq!(
+            parse_forward_path("<>", policy),
+            Err(SmtpPathError::NullPathNotAllowed)
+        );
+        assert_eq!(
+            parse_reverse_path("user@example.com", policy),
+            Err(SmtpPathError::MissingAngleBrackets)
+        );
+    }
+
+    #[test]
+    fn test_zone_id_policy_applies() {
+        let line = "RCPT TO:<admin@[IPv6:fe80::1%eth0]>";
+        assert_eq!(
+            EnvelopeCommand::parse(line),
+            Err(SmtpPathError::InvalidAddress(
+                EmailValidationError::IpZoneIdNotAllowed
+            ))
+        );
+
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        assert!(EnvelopeCommand::parse_with_policy(line, policy).is_ok());
+    }
+}
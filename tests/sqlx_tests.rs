@@ -0,0 +1,61 @@
+#![cfg(feature = "sqlx")]
+
+use email_syntax_verify_opt::{Email, EmailValidationError};
+use sqlx::sqlite::SqliteConnection;
+use sqlx::{Connection, Executor};
+
+async fn connection() -> SqliteConnection {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    conn.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT NOT NULL)")
+        .await
+        .unwrap();
+    conn
+}
+
+#[cfg(test)]
+mod sqlx_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let mut conn = connection().await;
+        let email = Email::parse("user@example.com").unwrap();
+
+        sqlx::query("INSERT INTO users (id, email) VALUES (1, ?)")
+            .bind(&email)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO users (id, email) VALUES (2, ?)")
+            .bind(Email::parse("other@example.org").unwrap())
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        let loaded: Vec<Email> = sqlx::query_scalar("SELECT email FROM users ORDER BY id")
+            .fetch_all(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(loaded, [email, Email::parse("other@example.org").unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_stored_value_fails_to_decode() {
+        let mut conn = connection().await;
+        conn.execute("INSERT INTO users (id, email) VALUES (1, 'user.example.com')")
+            .await
+            .unwrap();
+
+        let err = sqlx::query_scalar::<_, Email>("SELECT email FROM users")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap_err();
+        let sqlx::Error::ColumnDecode { source, .. } = err else {
+            panic!("expected a decode error, got {err:?}");
+        };
+        assert_eq!(
+            source.downcast_ref::<EmailValidationError>(),
+            Some(&EmailValidationError::NoAtSymbol)
+        );
+    }
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "std")]
+
+use email_syntax_verify_opt::{EmailStream, EmailValidationError, ValidationPolicy};
+use std::io::{self, BufRead, BufReader, Read};
+
+fn collect<R: BufRead>(
+    mut stream: EmailStream<R>,
+) -> Vec<(usize, String, Result<(), EmailValidationError>)> {
+    let mut entries = Vec::new();
+    while let Some(entry) = stream.next_entry() {
+        let (line_no, input, result) = entry.unwrap();
+        entries.push((
+            line_no,
+            String::from_utf8_lossy(input).into_owned(),
+            result.map(|_| ()),
+        ));
+    }
+    entries
+}
+
+/// Reader that fails once after yielding its first bytes.
+struct FlakyReader<'a> {
+    data: &'a [u8],
+    failed: bool,
+}
+
+impl Read for FlakyReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.data.len() < 10 && !self.failed {
+            self.failed = true;
+            return Err(io::Error::other("disk on fire"));
+        }
+        let len = buf.len().min(self.data.len()).min(4);
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use super::*;
+
+    #[test]
+    fn test_line_endings_and_bom() {
+        let input = "\u{FEFF}a@example.com\r\nnot-an-address\n\r\nb@example.org";
+        let entries = collect(EmailStream::new(input.as_bytes()));
+
+        assert_eq!(
+            entries,
+            [
+                (1, "a@example.com".to_owned(), Ok(())),
+                (
+                    2,
+                    "not-an-address".to_owned(),
+                    Err(EmailValidationError::NoAtSymbol)
+                ),
+                (3, String::new(), Err(EmailValidationError::Empty)),
+                (4, "b@example.org".to_owned(), Ok(())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bom_only_stripped_on_first_line() {
+        let input = "a@example.com\n\u{FEFF}b@example.com\n";
+        let entries = collect(EmailStream::new(input.as_bytes()));
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].2, Err(EmailValidationError::InvalidUserPart));
+    }
+
+    #[test]
+    fn test_bom_with_longest_address() {
+        let domain = ["a".repeat(63).as_str(); 4].join(".");
+        let address = format!("{}@{domain}", "u".repeat(64));
+        assert_eq!(address.len(), 320);
+
+        for ending in ["\n", "\r\n", ""] {
+            let input = format!("\u{FEFF}{address}{ending}");
+            let entries = collect(EmailStream::new(input.as_bytes()));
+            assert_eq!(entries, [(1, address.clone(), Ok(()))], "{ending:?}");
+        }
+
+        let input = format!("\u{FEFF}{address}x\r\n");
+        let entries = collect(EmailStream::new(input.as_bytes()));
+        assert_eq!(entries[0].2, Err(EmailValidationError::TooLong));
+    }
+
+    #[test]
+    fn test_small_reader_buffer() {
+        let lines: Vec<String> = (0..500).map(|i| format!("user{i}@example.com")).collect();
+        let input = lines.join("\r\n");
+        let reader = BufReader::with_capacity(7, input.as_bytes());
+        let entries = collect(EmailStream::new(reader));
+
+        assert_eq!(entries.len(), 500);
+        for (i, (line_no, line, result)) in entries.iter().enumerate() {
+            assert_eq!(*line_no, i + 1);
+            assert_eq!(*line, lines[i]);
+            assert_eq!(*result, Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_overlong_line_is_bounded() {
+        let input = format!("{}@example.com\nok@example.com\n", "a".repeat(1_000_000));
+        let mut stream = EmailStream::new(BufReader::with_capacity(64, input.as_bytes()));
+
+        let (line_no, line, result) = stream.next_entry().unwrap().unwrap();
+        assert_eq!(line_no, 1);
+        assert!(line.len() < 400);
+        assert_eq!(result, Err(EmailValidationError::TooLong));
+
+        let (line_no, line, result) = stream.next_entry().unwrap().unwrap();
+        assert_eq!(line_no, 2);
+        assert_eq!(line, b"ok@example.com");
+        assert!(result.is_ok());
+        assert!(stream.next_entry().is_none());
+    }
+
+    #[test]
+    fn test_policy_and_non_utf8_lines() {
+        let input = b"admin@[IPv6:fe80::1%eth0]\n\xff\xfe@example.com\n";
+
+        let policy = ValidationPolicy::new().allow_ip_zone_id(true);
+        let entries = collect(EmailStream::with_policy(&input[..], policy));
+        assert_eq!(entries[0].2, Ok(()));
+        assert_eq!(entries[1].2, Err(EmailValidationError::InvalidUserPart));
+    }
+
+    #[test]
+    fn test_io_errors_are_surfaced() {
+        let reader = BufReader::with_capacity(
+            4,
+            FlakyReader {
+                data: b"a@example.com\nb@example.com\n",
+                failed: false,
+            },
+        );
+        let mut stream = EmailStream::new(reader);
+
+        let (_, line, _) = stream.next_entry().unwrap().unwrap();
+        assert_eq!(line, b"a@example.com");
+        assert!(stream.next_entry().unwrap().is_err());
+        assert_eq!(stream.line_no(), 1);
+    }
+}
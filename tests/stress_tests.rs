@@ -1,3 +1,5 @@
+#![allow(clippy::useless_vec)]
+
 use email_syntax_verify_opt::ValidateEmail;
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -1,4 +1,6 @@
-use email_syntax_verify_opt::{EmailValidator, ValidateEmail};
+use email_syntax_verify_opt::{
+    EmailValidationError, EmailValidator, ValidateEmail, ValidationPolicy,
+};
 use std::collections::HashMap;
 
 mod test_data {
@@ -619,6 +621,126 @@ mod api_consistency_tests {
             "None should return true (permissive)"
         );
     }
+
+    #[test]
+    fn test_check_email_reports_error_under_policy() {
+        let zoned = "admin@[IPv6:fe80::1%eth0]";
+        let zones = ValidationPolicy::new().allow_ip_zone_id(true);
+
+        assert_eq!(
+            zoned.check_email(ValidationPolicy::DEFAULT),
+            Err(EmailValidationError::IpZoneIdNotAllowed)
+        );
+        assert_eq!(zoned.check_email(zones), Ok(()));
+        assert_eq!(zoned.as_bytes().check_email(zones), Ok(()));
+        assert_eq!(
+            String::from("user.example.com").check_email(zones),
+            Err(EmailValidationError::NoAtSymbol)
+        );
+        assert_eq!(None::<&str>.check_email(zones), Ok(()));
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            Some(Box::<str>::from("")).check_email(zones),
+            Err(EmailValidationError::Empty)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_batch_matches_single_validation() {
+        let emails: Vec<&str> = test_data::RFC5322_COMPLIANT_EMAILS
+            .iter()
+            .chain(test_data::INVALID_EMAILS)
+            .map(|case| case.email)
+            .collect();
+
+        let results = EmailValidator::validate_batch(&emails);
+        assert_eq!(results.len(), emails.len());
+        for (email, result) in emails.iter().zip(&results) {
+            assert_eq!(
+                *result,
+                EmailValidator::validate_str(email),
+                "Batch result should match single validation for: {}",
+                email
+            );
+        }
+
+        let owned: Vec<String> = emails.iter().map(|email| email.to_string()).collect();
+        assert_eq!(EmailValidator::validate_batch(&owned), results);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_batch_matches_single_validation_near_block_size() {
+        let alphabet = ["a", "1", ".", "-", "@", "_", "[", "é"];
+        let mut cores = vec![String::new()];
+        for _ in 0..4 {
+            let longer: Vec<String> = cores
+                .iter()
+                .flat_map(|core| alphabet.iter().map(move |c| format!("{core}{c}")))
+                .collect();
+            cores.extend(longer);
+        }
+
+        let mut emails = Vec::new();
+        for core in &cores {
+            for padding in [0, 20, 27, 28, 29] {
+                let pad = "b".repeat(padding);
+                emails.push(format!("{pad}{core}"));
+                emails.push(format!("{core}@{pad}x.io"));
+                emails.push(format!("u@{pad}{core}"));
+            }
+        }
+
+        let results = EmailValidator::validate_batch(&emails);
+        for (email, result) in emails.iter().zip(results) {
+            assert_eq!(result, EmailValidator::validate_str(email), "{email:?}");
+        }
+    }
+
+    #[test]
+    fn test_batch_into_reuses_buffer() {
+        let mut results = [true; 4];
+
+        let valid = EmailValidator::validate_batch_into(
+            &["a@example.com", "invalid", "b@example.org"],
+            &mut results,
+        );
+        assert_eq!(valid, 2);
+        assert_eq!(results, [true, false, true, true]);
+
+        let valid = EmailValidator::validate_batch_into(&["", "c@example.net"], &mut results);
+        assert_eq!(valid, 1);
+        assert_eq!(&results[..2], [false, true]);
+    }
+
+    #[test]
+    #[should_panic(expected = "result buffer")]
+    fn test_batch_into_rejects_short_buffer() {
+        let mut results = [false; 1];
+        EmailValidator::validate_batch_into(&["a@example.com", "b@example.com"], &mut results);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_canonical_form() {
+        let cases = [
+            ("User.Name@Example.COM", "User.Name@example.com", false),
+            ("user@münchen.de", "user@xn--mnchen-3ya.de", true),
+            ("user@[192.168.1.1]", "user@[192.168.1.1]", false),
+            (
+                "user@[IPv6:2001:DB8:0:0::1]",
+                "user@[IPv6:2001:db8::1]",
+                false,
+            ),
+        ];
+
+        for (input, canonical, is_idn) in cases {
+            let address = EmailValidator::parse_str(input).unwrap();
+            assert_eq!(address.canonical(), canonical, "for {input}");
+            assert_eq!(address.is_idn(), is_idn, "for {input}");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,54 @@
+#![cfg(feature = "validator")]
+
+use email_syntax_verify_opt::validator_compat::validate_email;
+use validator::{Validate, ValidationError};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(custom(function = "email_syntax_verify_opt::validator_compat::validate_email"))]
+    email: String,
+    #[validate(custom(function = "validate_email"))]
+    backup_email: Option<String>,
+}
+
+fn signup(email: &str, backup_email: Option<&str>) -> Signup {
+    Signup {
+        email: email.to_owned(),
+        backup_email: backup_email.map(str::to_owned),
+    }
+}
+
+#[cfg(test)]
+mod validator_compat_tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_struct_passes() {
+        assert!(signup("user@example.com", None).validate().is_ok());
+        assert!(signup("user@example.com", Some("alt@example.org"))
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_error_codes_are_mapped() {
+        let errors = signup("user.example.com", Some("alt@@example.org"))
+            .validate()
+            .unwrap_err();
+        let fields = errors.field_errors();
+
+        let email = &fields["email"][0];
+        assert_eq!(email.code, "no_at_symbol");
+        assert_eq!(email.message.as_deref(), Some("Email missing @ symbol"));
+        assert_eq!(fields["backup_email"][0].code, "multiple_at_symbols");
+    }
+
+    #[test]
+    fn test_plain_function_call() {
+        assert_eq!(validate_email("a@example.com"), Ok(()));
+        assert_eq!(
+            validate_email(&String::new()).unwrap_err(),
+            ValidationError::new("empty").with_message("Email is empty".into())
+        );
+    }
+}